@@ -8,8 +8,32 @@ pub const INITIAL_BASE_REWARD_RATE: u64 = 10u64.pow(3u32);
 /// The spam/liveness tolerance to initialize the program with.
 pub const INITIAL_TOLERANCE: i64 = 5;
 
-/// The minimum difficulty required of all submitted hashes.
-pub const MIN_DIFFICULTY: u32 = 8; // 12;
+/// The minimum difficulty to initialize the program with.
+pub const INITIAL_MIN_DIFFICULTY: u32 = 8;
+
+/// The lowest value the admin may set the minimum difficulty to.
+pub const MIN_DIFFICULTY_LOWER_BOUND: u32 = 1;
+
+/// The highest value the admin may set the minimum difficulty to.
+pub const MIN_DIFFICULTY_UPPER_BOUND: u32 = 32;
+
+/// Pause flag which halts the mine instruction.
+pub const PAUSE_MINE: u64 = 1 << 0;
+
+/// Pause flag which halts the reset instruction.
+pub const PAUSE_RESET: u64 = 1 << 1;
+
+/// Pause flag which halts the claim instruction.
+pub const PAUSE_CLAIM: u64 = 1 << 2;
+
+/// Pause flag which halts the stake and unstake instructions.
+pub const PAUSE_STAKE: u64 = 1 << 3;
+
+/// Pause flag which halts the upgrade instruction.
+pub const PAUSE_UPGRADE: u64 = 1 << 4;
+
+/// All pause flags.
+pub const PAUSE_ALL: u64 = PAUSE_MINE | PAUSE_RESET | PAUSE_CLAIM | PAUSE_STAKE | PAUSE_UPGRADE;
 
 /// The decimal precision of the Ore token.
 /// There are 100 billion indivisible units per Ore (called "grains").
@@ -27,43 +51,114 @@ pub const ONE_DAY: i64 = 86400;
 /// The duration of one year, in minutes.
 pub const ONE_YEAR: u64 = 525600;
 
-/// The number of minutes in an Ore epoch.
-pub const EPOCH_MINUTES: i64 = 1;
+/// The duration stake must remain deposited before it can be withdrawn, in seconds.
+pub const UNSTAKE_COOLDOWN: i64 = ONE_DAY;
+
+/// The stake age at which stake earns the full staking multiplier, in seconds.
+pub const STAKE_RAMP_DURATION: i64 = ONE_DAY.saturating_mul(7);
+
+/// The lock durations which may be chosen when staking, in seconds, indexed by lock tier.
+pub const STAKE_LOCK_DURATIONS: [i64; 4] = [
+    0,
+    ONE_DAY.saturating_mul(30),
+    ONE_DAY.saturating_mul(90),
+    ONE_DAY.saturating_mul(365),
+];
+
+/// The maximum staking bonus earned by locked stake, as a percentage of the base reward, indexed by lock tier.
+pub const STAKE_LOCK_BOOSTS: [u64; 4] = [100, 150, 200, 300];
+
+/// The vesting duration to initialize the program with, in seconds.
+pub const INITIAL_VESTING_DURATION: i64 = ONE_DAY;
+
+/// The shortest vesting duration the admin may set, in seconds.
+pub const MIN_VESTING_DURATION: i64 = ONE_DAY;
+
+/// The longest vesting duration the admin may set, in seconds.
+pub const MAX_VESTING_DURATION: i64 = ONE_DAY.saturating_mul(30);
+
+/// The epoch duration to initialize the program with, in seconds.
+pub const INITIAL_EPOCH_DURATION: i64 = ONE_MINUTE;
+
+/// The shortest epoch duration the admin may set, in seconds.
+pub const MIN_EPOCH_DURATION: i64 = ONE_MINUTE;
 
-/// The duration of an Ore epoch, in seconds.
-pub const EPOCH_DURATION: i64 = ONE_MINUTE.saturating_mul(EPOCH_MINUTES);
+/// The longest epoch duration the admin may set, in seconds.
+pub const MAX_EPOCH_DURATION: i64 = ONE_MINUTE.saturating_mul(5);
 
 /// The maximum token supply (42 million).
 pub const MAX_SUPPLY: u64 = ONE_ORE.saturating_mul(42_000_000);
 
-/// The target quantity of ORE to be mined per epoch.
-pub const TARGET_EPOCH_REWARDS: u64 = ONE_ORE.saturating_mul(EPOCH_MINUTES as u64);
+/// The target quantity of ORE to be mined per minute.
+pub const TARGET_REWARDS_PER_MINUTE: u64 = ONE_ORE;
 
-/// The maximum quantity of ORE that can be mined per epoch.
+/// The maximum quantity of ORE that can be mined per minute.
 /// Inflation rate ≈ 1 ORE / min (min 0, max 5)
-pub const MAX_EPOCH_REWARDS: u64 = TARGET_EPOCH_REWARDS.saturating_mul(5);
+pub const MAX_REWARDS_PER_MINUTE: u64 = TARGET_REWARDS_PER_MINUTE.saturating_mul(5);
 
-/// The quantity of ORE each bus is allowed to issue per epoch.
-pub const BUS_EPOCH_REWARDS: u64 = MAX_EPOCH_REWARDS.saturating_div(BUS_COUNT as u64);
+/// The maximum bounty that may be paid to the signer of a reset, per epoch.
+pub const MAX_RESET_BOUNTY: u64 = TARGET_REWARDS_PER_MINUTE.saturating_div(10);
 
-/// The number of bus accounts, for parallelizing mine operations.
-pub const BUS_COUNT: usize = 8;
+/// The number of bus accounts to initialize the program with, for parallelizing mine operations.
+pub const INITIAL_BUS_COUNT: usize = 8;
+
+/// The maximum number of bus accounts the admin may create.
+pub const MAX_BUS_COUNT: usize = 16;
+
+/// The longest window the reward rate moving average may span, in epochs.
+pub const MAX_RATE_EMA_WINDOW: u64 = 60;
+
+/// The number of ended epochs recorded by the epoch history account.
+pub const EPOCH_HISTORY_SIZE: usize = 128;
 
 /// The smoothing factor for reward rate changes. The reward rate cannot change by more or less
 /// than a factor of this constant from one epoch to the next.
 pub const SMOOTHING_FACTOR: u64 = 2;
 
-// Assert MAX_EPOCH_REWARDS is evenly divisible by BUS_COUNT.
+/// The most epochs a single reset adjusts the reward rate for. This is enough for the rate to move
+/// across the entire u64 range at the smoothing factor.
+pub const MAX_CATCH_UP_EPOCHS: u64 = 64;
+
+// Assert MAX_REWARDS_PER_MINUTE is evenly divisible by INITIAL_BUS_COUNT.
 static_assertions::const_assert!(
-    (MAX_EPOCH_REWARDS / BUS_COUNT as u64) * BUS_COUNT as u64 == MAX_EPOCH_REWARDS
+    (MAX_REWARDS_PER_MINUTE / INITIAL_BUS_COUNT as u64) * INITIAL_BUS_COUNT as u64
+        == MAX_REWARDS_PER_MINUTE
 );
 
+// Assert bus ids fit in a single byte PDA seed.
+static_assertions::const_assert!(MAX_BUS_COUNT <= u8::MAX as usize);
+
+/// The target quantity of ORE to be mined per epoch of the given duration.
+pub const fn target_epoch_rewards(epoch_duration: i64) -> u64 {
+    TARGET_REWARDS_PER_MINUTE.saturating_mul(epoch_duration as u64 / ONE_MINUTE as u64)
+}
+
+/// The maximum quantity of ORE that can be mined per epoch of the given duration.
+pub const fn max_epoch_rewards(epoch_duration: i64) -> u64 {
+    MAX_REWARDS_PER_MINUTE.saturating_mul(epoch_duration as u64 / ONE_MINUTE as u64)
+}
+
+/// The quantity of ORE each bus is allowed to issue per epoch of the given duration, when the
+/// maximum epoch rewards are split across the given number of busses.
+pub const fn bus_epoch_rewards(epoch_duration: i64, bus_count: u64) -> u64 {
+    if bus_count == 0 {
+        return 0;
+    }
+    max_epoch_rewards(epoch_duration).saturating_div(bus_count)
+}
+
+/// The layout version of mine events written to the transaction return data.
+pub const MINE_EVENT_VERSION: u64 = 2;
+
 /// The seed of the bus account PDA.
 pub const BUS: &[u8] = b"bus";
 
 /// The seed of the config account PDA.
 pub const CONFIG: &[u8] = b"config";
 
+/// The seed of the epoch history account PDA.
+pub const EPOCH_HISTORY: &[u8] = b"epoch_history";
+
 /// The seed of the metadata account PDA.
 pub const METADATA: &[u8] = b"metadata";
 
@@ -76,6 +171,9 @@ pub const PROOF: &[u8] = b"proof";
 /// The seed of the treasury account PDA.
 pub const TREASURY: &[u8] = b"treasury";
 
+/// The seed of vesting account PDAs.
+pub const VESTING: &[u8] = b"vesting";
+
 /// Noise for deriving the mint pda
 pub const MINT_NOISE: [u8; 16] = [
     166, 199, 85, 221, 225, 119, 21, 185, 160, 82, 242, 237, 194, 84, 250, 252,
@@ -90,11 +188,14 @@ pub const METADATA_SYMBOL: &str = "ORE";
 /// The uri for token metdata.
 pub const METADATA_URI: &str = "https://ore.supply/metadata.json";
 
+/// The only authority allowed to initialize the program.
+pub const INITIALIZER_ADDRESS: Pubkey = pubkey!("AeNqnoLwFanMd3ig9WoMxQZVwQHtCtqKMMBsT1sTrvz6");
+
 /// Program id for const pda derivations
 const PROGRAM_ID: [u8; 32] = unsafe { *(&crate::id() as *const Pubkey as *const [u8; 32]) };
 
-/// The addresses of the bus accounts.
-pub const BUS_ADDRESSES: [Pubkey; BUS_COUNT] = array_const_fn_init![const_bus_address; 8];
+/// The addresses of all bus accounts which may be created, indexed by bus id.
+pub const BUS_ADDRESSES: [Pubkey; MAX_BUS_COUNT] = array_const_fn_init![const_bus_address; 16];
 
 /// Function to derive const bus addresses.
 const fn const_bus_address(i: usize) -> Pubkey {
@@ -105,6 +206,10 @@ const fn const_bus_address(i: usize) -> Pubkey {
 pub const CONFIG_ADDRESS: Pubkey =
     Pubkey::new_from_array(ed25519::derive_program_address(&[CONFIG], &PROGRAM_ID).0);
 
+/// The address of the epoch history account.
+pub const EPOCH_HISTORY_ADDRESS: Pubkey =
+    Pubkey::new_from_array(ed25519::derive_program_address(&[EPOCH_HISTORY], &PROGRAM_ID).0);
+
 /// The address of the mint metadata account.
 pub const METADATA_ADDRESS: Pubkey = Pubkey::new_from_array(
     ed25519::derive_program_address(
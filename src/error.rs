@@ -5,7 +5,7 @@ use thiserror::Error;
 #[derive(Debug, Error, Clone, Copy, PartialEq, Eq, IntoPrimitive)]
 #[repr(u32)]
 pub enum OreError {
-    #[error("Mining is paused")]
+    #[error("This instruction is paused")]
     IsPaused = 0,
     #[error("The epoch has ended and needs reset")]
     NeedsReset = 1,
@@ -23,6 +23,30 @@ pub enum OreError {
     ToleranceOverflow = 7,
     #[error("The maximum supply has been reached")]
     MaxSupply = 8,
+    #[error("The minimum difficulty is outside the allowed range")]
+    DifficultyOutOfBounds = 9,
+    #[error("The unstake amount cannot be greater than the staked balance")]
+    UnstakeTooLarge = 10,
+    #[error("Stake cannot be withdrawn until the cooldown has elapsed")]
+    UnstakeCooldown = 11,
+    #[error("The reset bounty cannot exceed the maximum reset bounty")]
+    ResetBountyTooLarge = 12,
+    #[error("The epoch duration is outside the allowed range")]
+    EpochDurationOutOfBounds = 13,
+    #[error("The maximum number of busses has been reached")]
+    MaxBusCount = 14,
+    #[error("The reward rate EMA window is outside the allowed range")]
+    RateEmaWindowOutOfBounds = 15,
+    #[error("The stake lock tier is invalid")]
+    StakeLockInvalid = 16,
+    #[error("Locked stake cannot be withdrawn until the lock expires")]
+    StakeLocked = 17,
+    #[error("The vesting duration is outside the allowed range")]
+    VestingDurationOutOfBounds = 18,
+    #[error("The withdraw amount cannot be greater than the vested balance")]
+    WithdrawTooLarge = 19,
+    #[error("The stake amount must be greater than zero")]
+    StakeTooSmall = 20,
 }
 
 impl From<OreError> for ProgramError {
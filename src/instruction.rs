@@ -10,7 +10,8 @@ use solana_program::{
 
 use crate::{
     impl_instruction_from_bytes, impl_to_bytes, BUS, BUS_ADDRESSES, CONFIG, CONFIG_ADDRESS,
-    METADATA, MINT, MINT_ADDRESS, MINT_NOISE, PROOF, TREASURY, TREASURY_ADDRESS,
+    EPOCH_HISTORY, EPOCH_HISTORY_ADDRESS, METADATA, MINT, MINT_ADDRESS, MINT_NOISE,
+    MINT_V1_ADDRESS, PROOF, TREASURY, TREASURY_ADDRESS, VESTING,
 };
 
 #[repr(u8)]
@@ -19,19 +20,14 @@ use crate::{
 pub enum OreInstruction {
     #[account(0, name = "ore_program", desc = "Ore program")]
     #[account(1, name = "signer", desc = "Signer", signer)]
-    #[account(2, name = "bus_0", desc = "Ore bus account 0", writable)]
-    #[account(3, name = "bus_1", desc = "Ore bus account 1", writable)]
-    #[account(4, name = "bus_2", desc = "Ore bus account 2", writable)]
-    #[account(5, name = "bus_3", desc = "Ore bus account 3", writable)]
-    #[account(6, name = "bus_4", desc = "Ore bus account 4", writable)]
-    #[account(7, name = "bus_5", desc = "Ore bus account 5", writable)]
-    #[account(8, name = "bus_6", desc = "Ore bus account 6", writable)]
-    #[account(9, name = "bus_7", desc = "Ore bus account 7", writable)]
-    #[account(10, name = "config", desc = "Ore config account")]
-    #[account(11, name = "mint", desc = "Ore token mint account", writable)]
-    #[account(12, name = "treasury", desc = "Ore treasury account", writable)]
-    #[account(13, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
-    #[account(14, name = "token_program", desc = "SPL token program")]
+    #[account(2, name = "beneficiary", desc = "Beneficiary token account for the reset bounty", writable)]
+    #[account(3, name = "config", desc = "Ore config account", writable)]
+    #[account(4, name = "epoch_history", desc = "Ore epoch history account", writable)]
+    #[account(5, name = "mint", desc = "Ore token mint account", writable)]
+    #[account(6, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(7, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
+    #[account(8, name = "token_program", desc = "SPL token program")]
+    #[account(9, name = "bus_0", desc = "Ore bus account 0, followed by every other bus account in order of id")]
     Reset = 0,
 
     #[account(0, name = "ore_program", desc = "Ore program")]
@@ -52,28 +48,33 @@ pub enum OreInstruction {
     #[account(0, name = "ore_program", desc = "Ore program")]
     #[account(1, name = "signer", desc = "Signer", signer)]
     #[account(2, name = "beneficiary", desc = "Beneficiary token account", writable)]
-    #[account(3, name = "proof", desc = "Ore proof account", writable)]
-    #[account(4, name = "treasury", desc = "Ore treasury account", writable)]
-    #[account(5, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
-    #[account(6, name = "token_program", desc = "SPL token program")]
+    #[account(3, name = "config", desc = "Ore config account")]
+    #[account(4, name = "mint", desc = "Ore token mint account", writable)]
+    #[account(5, name = "proof", desc = "Ore proof account", writable)]
+    #[account(6, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(7, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
+    #[account(8, name = "token_program", desc = "SPL token program")]
     Claim = 3,
 
     #[account(0, name = "ore_program", desc = "Ore program")]
     #[account(1, name = "signer", desc = "Signer", signer)]
-    #[account(2, name = "proof", desc = "Ore proof account", writable)]
-    #[account(3, name = "sender", desc = "Signer token account", writable)]
-    #[account(4, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
-    #[account(5, name = "token_program", desc = "SPL token program")]
+    #[account(2, name = "config", desc = "Ore config account")]
+    #[account(3, name = "proof", desc = "Ore proof account of the receiving miner", writable)]
+    #[account(4, name = "sender", desc = "Signer token account", writable)]
+    #[account(5, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(6, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
+    #[account(7, name = "token_program", desc = "SPL token program")]
     Stake = 4,
 
     #[account(0, name = "ore_program", desc = "Ore program")]
     #[account(1, name = "signer", desc = "Signer", signer)]
     #[account(2, name = "beneficiary", desc = "Beneficiary token account", writable)]
-    #[account(3, name = "sender", desc = "Signer token account", writable)]
-    #[account(4, name = "treasury", desc = "Ore treasury account", writable)]
-    #[account(5, name = "mint", desc = "Ore token mint account", writable)]
-    #[account(6, name = "mint_v1", desc = "Ore v1 token mint account", writable)]
-    #[account(7, name = "token_program", desc = "SPL token program")]
+    #[account(3, name = "config", desc = "Ore config account")]
+    #[account(4, name = "mint", desc = "Ore token mint account", writable)]
+    #[account(5, name = "mint_v1", desc = "Ore v1 token mint account", writable)]
+    #[account(6, name = "sender", desc = "Signer token account", writable)]
+    #[account(7, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(8, name = "token_program", desc = "SPL token program")]
     Upgrade = 5,
 
     #[account(0, name = "ore_program", desc = "Ore program")]
@@ -81,6 +82,46 @@ pub enum OreInstruction {
     #[account(2, name = "proof", desc = "Ore proof account", writable)]
     #[account(3, name = "system_program", desc = "Solana system program")]
     Deregister = 6,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Signer", signer)]
+    #[account(2, name = "proof", desc = "Ore proof account", writable)]
+    UpdateMiner = 7,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Signer", signer)]
+    #[account(2, name = "beneficiary", desc = "Beneficiary token account", writable)]
+    #[account(3, name = "config", desc = "Ore config account")]
+    #[account(4, name = "proof", desc = "Ore proof account", writable)]
+    #[account(5, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(6, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
+    #[account(7, name = "token_program", desc = "SPL token program")]
+    Unstake = 8,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account")]
+    #[account(3, name = "proof", desc = "Ore proof account", writable)]
+    #[account(4, name = "treasury", desc = "Ore treasury account", writable)]
+    Compound = 9,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account")]
+    #[account(3, name = "proof", desc = "Ore proof account", writable)]
+    #[account(4, name = "vesting", desc = "Ore vesting account", writable)]
+    #[account(5, name = "system_program", desc = "Solana system program")]
+    Vest = 10,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Signer", signer)]
+    #[account(2, name = "beneficiary", desc = "Beneficiary token account", writable)]
+    #[account(3, name = "config", desc = "Ore config account")]
+    #[account(4, name = "vesting", desc = "Ore vesting account", writable)]
+    #[account(5, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(6, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
+    #[account(7, name = "token_program", desc = "SPL token program")]
+    Withdraw = 11,
     
     #[account(0, name = "ore_program", desc = "Ore program")]
     #[account(1, name = "signer", desc = "Admin signer", signer)]
@@ -92,16 +133,17 @@ pub enum OreInstruction {
     #[account(7, name = "bus_5", desc = "Ore bus account 5", writable)]
     #[account(8, name = "bus_6", desc = "Ore bus account 6", writable)]
     #[account(9, name = "bus_7", desc = "Ore bus account 7", writable)]
-    #[account(10, name = "metadata", desc = "Ore mint metadata account", writable)]
-    #[account(11, name = "mint", desc = "Ore mint account", writable)]
-    #[account(12, name = "noise", desc = "Ore noise account", writable)]
-    #[account(13, name = "treasury", desc = "Ore treasury account", writable)]
-    #[account(14, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
-    #[account(15, name = "system_program", desc = "Solana system program")]
-    #[account(16, name = "token_program", desc = "SPL token program")]
-    #[account(17, name = "associated_token_program", desc = "SPL associated token program")]
-    #[account(18, name = "mpl_metadata_program", desc = "Metaplex metadata program")]
-    #[account(19, name = "rent", desc = "Solana rent sysvar")]
+    #[account(10, name = "config", desc = "Ore config account", writable)]
+    #[account(11, name = "epoch_history", desc = "Ore epoch history account", writable)]
+    #[account(12, name = "metadata", desc = "Ore mint metadata account", writable)]
+    #[account(13, name = "mint", desc = "Ore mint account", writable)]
+    #[account(14, name = "treasury", desc = "Ore treasury account", writable)]
+    #[account(15, name = "treasury_tokens", desc = "Ore treasury token account", writable)]
+    #[account(16, name = "system_program", desc = "Solana system program")]
+    #[account(17, name = "token_program", desc = "SPL token program")]
+    #[account(18, name = "associated_token_program", desc = "SPL associated token program")]
+    #[account(19, name = "mpl_metadata_program", desc = "Metaplex metadata program")]
+    #[account(20, name = "rent", desc = "Solana rent sysvar")]
     Initialize = 100,
 
     #[account(0, name = "ore_program", desc = "Ore program")]
@@ -118,6 +160,43 @@ pub enum OreInstruction {
     #[account(1, name = "signer", desc = "Admin signer", signer)]
     #[account(2, name = "config", desc = "Ore config account", writable)]
     Pause = 103,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Admin signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account", writable)]
+    UpdateMinDifficulty = 104,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Pending admin signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account", writable)]
+    AcceptAdmin = 105,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Admin signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account", writable)]
+    UpdateResetBounty = 106,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Admin signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account", writable)]
+    UpdateEpochDuration = 107,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Admin signer", signer)]
+    #[account(2, name = "bus", desc = "Ore bus account", writable)]
+    #[account(3, name = "config", desc = "Ore config account", writable)]
+    #[account(4, name = "system_program", desc = "Solana system program")]
+    AddBus = 108,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Admin signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account", writable)]
+    UpdateRateEmaWindow = 109,
+
+    #[account(0, name = "ore_program", desc = "Ore program")]
+    #[account(1, name = "signer", desc = "Admin signer", signer)]
+    #[account(2, name = "config", desc = "Ore config account", writable)]
+    UpdateVestingDuration = 110,
 }
 
 impl OreInstruction {
@@ -138,6 +217,7 @@ pub struct InitializeArgs {
     pub bus_6_bump: u8,
     pub bus_7_bump: u8,
     pub config_bump: u8,
+    pub epoch_history_bump: u8,
     pub metadata_bump: u8,
     pub mint_bump: u8,
     pub treasury_bump: u8,
@@ -166,6 +246,32 @@ pub struct ClaimArgs {
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct StakeArgs {
     pub amount: [u8; 8],
+    pub lock_tier: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UnstakeArgs {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CompoundArgs {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct VestArgs {
+    pub amount: [u8; 8],
+    pub bump: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct WithdrawArgs {
+    pub amount: [u8; 8],
 }
 
 #[repr(C)]
@@ -174,6 +280,12 @@ pub struct UpgradeArgs {
     pub amount: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateMinerArgs {
+    pub new_miner: Pubkey,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct UpdateAdminArgs {
@@ -190,7 +302,43 @@ pub struct UpdateToleranceArgs {
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct PauseArgs {
-    pub paused: u8,
+    pub paused: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateMinDifficultyArgs {
+    pub min_difficulty: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateResetBountyArgs {
+    pub reset_bounty: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateEpochDurationArgs {
+    pub epoch_duration: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AddBusArgs {
+    pub bump: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateRateEmaWindowArgs {
+    pub rate_ema_window: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateVestingDurationArgs {
+    pub vesting_duration: u64,
 }
 
 impl_to_bytes!(InitializeArgs);
@@ -198,45 +346,68 @@ impl_to_bytes!(RegisterArgs);
 impl_to_bytes!(MineArgs);
 impl_to_bytes!(ClaimArgs);
 impl_to_bytes!(StakeArgs);
+impl_to_bytes!(UnstakeArgs);
+impl_to_bytes!(CompoundArgs);
+impl_to_bytes!(VestArgs);
+impl_to_bytes!(WithdrawArgs);
 impl_to_bytes!(UpgradeArgs);
+impl_to_bytes!(UpdateMinerArgs);
 impl_to_bytes!(UpdateAdminArgs);
 impl_to_bytes!(UpdateToleranceArgs);
 impl_to_bytes!(PauseArgs);
+impl_to_bytes!(UpdateMinDifficultyArgs);
+impl_to_bytes!(UpdateResetBountyArgs);
+impl_to_bytes!(UpdateEpochDurationArgs);
+impl_to_bytes!(AddBusArgs);
+impl_to_bytes!(UpdateRateEmaWindowArgs);
+impl_to_bytes!(UpdateVestingDurationArgs);
 
 impl_instruction_from_bytes!(InitializeArgs);
 impl_instruction_from_bytes!(RegisterArgs);
 impl_instruction_from_bytes!(MineArgs);
 impl_instruction_from_bytes!(ClaimArgs);
 impl_instruction_from_bytes!(StakeArgs);
+impl_instruction_from_bytes!(UnstakeArgs);
+impl_instruction_from_bytes!(CompoundArgs);
+impl_instruction_from_bytes!(VestArgs);
+impl_instruction_from_bytes!(WithdrawArgs);
 impl_instruction_from_bytes!(UpgradeArgs);
+impl_instruction_from_bytes!(UpdateMinerArgs);
 impl_instruction_from_bytes!(UpdateAdminArgs);
 impl_instruction_from_bytes!(UpdateToleranceArgs);
 impl_instruction_from_bytes!(PauseArgs);
-
-/// Builds a reset instruction.
-pub fn reset(signer: Pubkey) -> Instruction {
+impl_instruction_from_bytes!(UpdateMinDifficultyArgs);
+impl_instruction_from_bytes!(UpdateResetBountyArgs);
+impl_instruction_from_bytes!(UpdateEpochDurationArgs);
+impl_instruction_from_bytes!(AddBusArgs);
+impl_instruction_from_bytes!(UpdateRateEmaWindowArgs);
+impl_instruction_from_bytes!(UpdateVestingDurationArgs);
+
+/// Builds a reset instruction for a program with the given number of busses. The reset bounty is
+/// paid to the signer's associated token account.
+pub fn reset(signer: Pubkey, bus_count: u64) -> Instruction {
+    let beneficiary =
+        spl_associated_token_account::get_associated_token_address(&signer, &MINT_ADDRESS);
     let treasury_tokens = spl_associated_token_account::get_associated_token_address(
         &TREASURY_ADDRESS,
         &MINT_ADDRESS,
     );
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(beneficiary, false),
+        AccountMeta::new(CONFIG_ADDRESS, false),
+        AccountMeta::new(EPOCH_HISTORY_ADDRESS, false),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new(TREASURY_ADDRESS, false),
+        AccountMeta::new(treasury_tokens, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for bus in BUS_ADDRESSES.iter().take(bus_count as usize) {
+        accounts.push(AccountMeta::new_readonly(*bus, false));
+    }
     Instruction {
         program_id: crate::id(),
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(BUS_ADDRESSES[0], false),
-            AccountMeta::new(BUS_ADDRESSES[1], false),
-            AccountMeta::new(BUS_ADDRESSES[2], false),
-            AccountMeta::new(BUS_ADDRESSES[3], false),
-            AccountMeta::new(BUS_ADDRESSES[4], false),
-            AccountMeta::new(BUS_ADDRESSES[5], false),
-            AccountMeta::new(BUS_ADDRESSES[6], false),
-            AccountMeta::new(BUS_ADDRESSES[7], false),
-            AccountMeta::new(CONFIG_ADDRESS, false),
-            AccountMeta::new(MINT_ADDRESS, false),
-            AccountMeta::new(TREASURY_ADDRESS, false),
-            AccountMeta::new(treasury_tokens, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
+        accounts,
         data: OreInstruction::Reset.to_vec(),
     }
 }
@@ -275,8 +446,8 @@ pub fn deregister(signer: Pubkey) -> Instruction {
 }
 
 /// Builds a mine instruction.
-pub fn mine(signer: Pubkey, bus: Pubkey, solution: Solution) -> Instruction {
-    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+pub fn mine(signer: Pubkey, authority: Pubkey, bus: Pubkey, solution: Solution) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, authority.as_ref()], &crate::id()).0;
     Instruction {
         program_id: crate::id(),
         accounts: vec![
@@ -312,9 +483,10 @@ pub fn claim(signer: Pubkey, beneficiary: Pubkey, amount: u64) -> Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(beneficiary, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
             AccountMeta::new(MINT_ADDRESS, false),
             AccountMeta::new(proof, false),
-            AccountMeta::new_readonly(TREASURY_ADDRESS, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
             AccountMeta::new(treasury_tokens, false),
             AccountMeta::new_readonly(spl_token::id(), false),
         ],
@@ -330,9 +502,16 @@ pub fn claim(signer: Pubkey, beneficiary: Pubkey, amount: u64) -> Instruction {
     }
 }
 
-/// Build a stake instruction.
-pub fn stake(signer: Pubkey, sender: Pubkey, amount: u64) -> Instruction {
-    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+/// Build a stake instruction. The authority is the owner of the proof receiving the deposit, which may
+/// differ from the signer. The lock tier indexes the stake lock durations, where tier 0 is unlocked.
+pub fn stake(
+    signer: Pubkey,
+    authority: Pubkey,
+    sender: Pubkey,
+    amount: u64,
+    lock_tier: u8,
+) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, authority.as_ref()], &crate::id()).0;
     let treasury_tokens = spl_associated_token_account::get_associated_token_address(
         &TREASURY_ADDRESS,
         &MINT_ADDRESS,
@@ -341,8 +520,10 @@ pub fn stake(signer: Pubkey, sender: Pubkey, amount: u64) -> Instruction {
         program_id: crate::id(),
         accounts: vec![
             AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
             AccountMeta::new(proof, false),
             AccountMeta::new(sender, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
             AccountMeta::new(treasury_tokens, false),
             AccountMeta::new_readonly(spl_token::id(), false),
         ],
@@ -350,6 +531,33 @@ pub fn stake(signer: Pubkey, sender: Pubkey, amount: u64) -> Instruction {
             OreInstruction::Stake.to_vec(),
             StakeArgs {
                 amount: amount.to_le_bytes(),
+                lock_tier,
+            }
+            .to_bytes()
+            .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an upgrade instruction.
+pub fn upgrade(signer: Pubkey, beneficiary: Pubkey, sender: Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(beneficiary, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(MINT_V1_ADDRESS, false),
+            AccountMeta::new(sender, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: [
+            OreInstruction::Upgrade.to_vec(),
+            UpgradeArgs {
+                amount: amount.to_le_bytes(),
             }
             .to_bytes()
             .to_vec(),
@@ -358,6 +566,133 @@ pub fn stake(signer: Pubkey, sender: Pubkey, amount: u64) -> Instruction {
     }
 }
 
+/// Build an unstake instruction.
+pub fn unstake(signer: Pubkey, beneficiary: Pubkey, amount: u64) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+    let treasury_tokens = spl_associated_token_account::get_associated_token_address(
+        &TREASURY_ADDRESS,
+        &MINT_ADDRESS,
+    );
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(beneficiary, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+            AccountMeta::new(proof, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new(treasury_tokens, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: [
+            OreInstruction::Unstake.to_vec(),
+            UnstakeArgs {
+                amount: amount.to_le_bytes(),
+            }
+            .to_bytes()
+            .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a compound instruction.
+pub fn compound(signer: Pubkey, amount: u64) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+            AccountMeta::new(proof, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+        ],
+        data: [
+            OreInstruction::Compound.to_vec(),
+            CompoundArgs {
+                amount: amount.to_le_bytes(),
+            }
+            .to_bytes()
+            .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a vest instruction.
+pub fn vest(signer: Pubkey, amount: u64) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+    let vesting_pda = Pubkey::find_program_address(&[VESTING, proof.as_ref()], &crate::id());
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+            AccountMeta::new(proof, false),
+            AccountMeta::new(vesting_pda.0, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: [
+            OreInstruction::Vest.to_vec(),
+            VestArgs {
+                amount: amount.to_le_bytes(),
+                bump: vesting_pda.1,
+            }
+            .to_bytes()
+            .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a withdraw instruction.
+pub fn withdraw(signer: Pubkey, beneficiary: Pubkey, amount: u64) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+    let vesting = Pubkey::find_program_address(&[VESTING, proof.as_ref()], &crate::id()).0;
+    let treasury_tokens = spl_associated_token_account::get_associated_token_address(
+        &TREASURY_ADDRESS,
+        &MINT_ADDRESS,
+    );
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(beneficiary, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+            AccountMeta::new(vesting, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new(treasury_tokens, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: [
+            OreInstruction::Withdraw.to_vec(),
+            WithdrawArgs {
+                amount: amount.to_le_bytes(),
+            }
+            .to_bytes()
+            .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Builds an update_miner instruction.
+pub fn update_miner(signer: Pubkey, new_miner: Pubkey) -> Instruction {
+    let proof = Pubkey::find_program_address(&[PROOF, signer.as_ref()], &crate::id()).0;
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(proof, false),
+        ],
+        data: [
+            OreInstruction::UpdateMiner.to_vec(),
+            UpdateMinerArgs { new_miner }.to_bytes().to_vec(),
+        ]
+        .concat(),
+    }
+}
+
 /// Builds an initialize instruction.
 pub fn initialize(signer: Pubkey) -> Instruction {
     let bus_pdas = [
@@ -371,6 +706,7 @@ pub fn initialize(signer: Pubkey) -> Instruction {
         Pubkey::find_program_address(&[BUS, &[7]], &crate::id()),
     ];
     let config_pda = Pubkey::find_program_address(&[CONFIG], &crate::id());
+    let epoch_history_pda = Pubkey::find_program_address(&[EPOCH_HISTORY], &crate::id());
     let mint_pda = Pubkey::find_program_address(&[MINT, MINT_NOISE.as_slice()], &crate::id());
     let treasury_pda = Pubkey::find_program_address(&[TREASURY], &crate::id());
     let treasury_tokens =
@@ -396,6 +732,7 @@ pub fn initialize(signer: Pubkey) -> Instruction {
             AccountMeta::new(bus_pdas[6].0, false),
             AccountMeta::new(bus_pdas[7].0, false),
             AccountMeta::new(config_pda.0, false),
+            AccountMeta::new(epoch_history_pda.0, false),
             AccountMeta::new(metadata_pda.0, false),
             AccountMeta::new(mint_pda.0, false),
             AccountMeta::new(treasury_pda.0, false),
@@ -418,6 +755,7 @@ pub fn initialize(signer: Pubkey) -> Instruction {
                 bus_6_bump: bus_pdas[6].1,
                 bus_7_bump: bus_pdas[7].1,
                 config_bump: config_pda.1,
+                epoch_history_bump: epoch_history_pda.1,
                 metadata_bump: metadata_pda.1,
                 mint_bump: mint_pda.1,
                 treasury_bump: treasury_pda.1,
@@ -445,6 +783,23 @@ pub fn update_admin(signer: Pubkey, new_admin: Pubkey) -> Instruction {
     }
 }
 
+/// Build an accept_admin instruction.
+pub fn accept_admin(signer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+        ],
+        data: OreInstruction::AcceptAdmin.to_vec(),
+    }
+}
+
+/// Build an update_admin instruction which cancels any pending admin proposal.
+pub fn cancel_admin(signer: Pubkey) -> Instruction {
+    update_admin(signer, Pubkey::default())
+}
+
 /// Build an update_tolerance instruction.
 pub fn update_tolerance(
     signer: Pubkey,
@@ -470,8 +825,8 @@ pub fn update_tolerance(
     }
 }
 
-/// Build a pause instruction.
-pub fn pause(signer: Pubkey, paused: bool) -> Instruction {
+/// Build a pause instruction. The paused argument is a set of `PAUSE_*` flags.
+pub fn pause(signer: Pubkey, paused: u64) -> Instruction {
     Instruction {
         program_id: crate::id(),
         accounts: vec![
@@ -479,12 +834,115 @@ pub fn pause(signer: Pubkey, paused: bool) -> Instruction {
             AccountMeta::new(CONFIG_ADDRESS, false),
         ],
         data: [
-            OreInstruction::UpdateAdmin.to_vec(),
-            PauseArgs {
-                paused: paused as u8,
-            }
-            .to_bytes()
-            .to_vec(),
+            OreInstruction::Pause.to_vec(),
+            PauseArgs { paused }.to_bytes().to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an update_min_difficulty instruction.
+pub fn update_min_difficulty(signer: Pubkey, min_difficulty: u64) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+        ],
+        data: [
+            OreInstruction::UpdateMinDifficulty.to_vec(),
+            UpdateMinDifficultyArgs { min_difficulty }
+                .to_bytes()
+                .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an update_reset_bounty instruction.
+pub fn update_reset_bounty(signer: Pubkey, reset_bounty: u64) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+        ],
+        data: [
+            OreInstruction::UpdateResetBounty.to_vec(),
+            UpdateResetBountyArgs { reset_bounty }.to_bytes().to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an update_epoch_duration instruction. The epoch duration is denominated in seconds.
+pub fn update_epoch_duration(signer: Pubkey, epoch_duration: u64) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+        ],
+        data: [
+            OreInstruction::UpdateEpochDuration.to_vec(),
+            UpdateEpochDurationArgs { epoch_duration }
+                .to_bytes()
+                .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an add_bus instruction. The bus id is the current bus count.
+pub fn add_bus(signer: Pubkey, id: u64) -> Instruction {
+    let bus_pda = Pubkey::find_program_address(&[BUS, &[id as u8]], &crate::id());
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(bus_pda.0, false),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: [
+            OreInstruction::AddBus.to_vec(),
+            AddBusArgs { bump: bus_pda.1 }.to_bytes().to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an update_rate_ema_window instruction. The window is denominated in epochs.
+pub fn update_rate_ema_window(signer: Pubkey, rate_ema_window: u64) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+        ],
+        data: [
+            OreInstruction::UpdateRateEmaWindow.to_vec(),
+            UpdateRateEmaWindowArgs { rate_ema_window }
+                .to_bytes()
+                .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an update_vesting_duration instruction.
+pub fn update_vesting_duration(signer: Pubkey, vesting_duration: u64) -> Instruction {
+    Instruction {
+        program_id: crate::id(),
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(CONFIG_ADDRESS, false),
+        ],
+        data: [
+            OreInstruction::UpdateVestingDuration.to_vec(),
+            UpdateVestingDurationArgs { vesting_duration }
+                .to_bytes()
+                .to_vec(),
         ]
         .concat(),
     }
@@ -14,9 +14,6 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey,
 };
 
-// TODO Initialize with mining paused
-// TODO Require hardcoded admin key for initialization
-
 declare_id!("mineQW6HcBby3YyZMTaRRtuFWPaGEg8AjmCAWs4nBU8");
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -43,10 +40,32 @@ pub fn process_instruction(
         OreInstruction::Claim => process_claim(program_id, accounts, data)?,
         OreInstruction::Stake => process_stake(program_id, accounts, data)?,
         OreInstruction::Upgrade => process_upgrade(program_id, accounts, data)?,
+        OreInstruction::UpdateMiner => process_update_miner(program_id, accounts, data)?,
+        OreInstruction::Unstake => process_unstake(program_id, accounts, data)?,
+        OreInstruction::Compound => process_compound(program_id, accounts, data)?,
+        OreInstruction::Vest => process_vest(program_id, accounts, data)?,
+        OreInstruction::Withdraw => process_withdraw(program_id, accounts, data)?,
         OreInstruction::Initialize => process_initialize(program_id, accounts, data)?,
         OreInstruction::UpdateAdmin => process_update_admin(program_id, accounts, data)?,
         OreInstruction::UpdateTolerance => process_update_tolerance(program_id, accounts, data)?,
         OreInstruction::Pause => process_pause(program_id, accounts, data)?,
+        OreInstruction::UpdateMinDifficulty => {
+            process_update_min_difficulty(program_id, accounts, data)?
+        }
+        OreInstruction::AcceptAdmin => process_accept_admin(program_id, accounts, data)?,
+        OreInstruction::UpdateResetBounty => {
+            process_update_reset_bounty(program_id, accounts, data)?
+        }
+        OreInstruction::UpdateEpochDuration => {
+            process_update_epoch_duration(program_id, accounts, data)?
+        }
+        OreInstruction::AddBus => process_add_bus(program_id, accounts, data)?,
+        OreInstruction::UpdateRateEmaWindow => {
+            process_update_rate_ema_window(program_id, accounts, data)?
+        }
+        OreInstruction::UpdateVestingDuration => {
+            process_update_vesting_duration(program_id, accounts, data)?
+        }
     }
 
     Ok(())
@@ -5,9 +5,9 @@ use solana_program::{
 use spl_token::state::Mint;
 
 use crate::{
-    state::{Bus, Config, Proof, Treasury},
+    state::{Bus, Config, EpochHistory, Proof, Treasury, Vesting},
     utils::{AccountDeserialize, Discriminator},
-    BUS_ADDRESSES, CONFIG_ADDRESS, TREASURY_ADDRESS,
+    BUS_ADDRESSES, CONFIG_ADDRESS, EPOCH_HISTORY_ADDRESS, TREASURY_ADDRESS, VESTING,
 };
 
 /// Errors if:
@@ -36,7 +36,7 @@ pub fn load_bus<'a, 'info>(
         return Err(ProgramError::InvalidAccountOwner);
     }
 
-    if info.key.ne(&BUS_ADDRESSES[id as usize]) {
+    if BUS_ADDRESSES.get(id as usize).ne(&Some(info.key)) {
         return Err(ProgramError::InvalidSeeds);
     }
 
@@ -125,6 +125,39 @@ pub fn load_config<'a, 'info>(
     Ok(())
 }
 
+/// Errors if:
+/// - Owner is not Ore program.
+/// - Address does not match the expected address.
+/// - Data is empty.
+/// - Data cannot deserialize into an epoch history account.
+/// - Expected to be writable, but is not.
+pub fn load_epoch_history<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if info.key.ne(&EPOCH_HISTORY_ADDRESS) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    if info.data.borrow()[0].ne(&(EpochHistory::discriminator() as u8)) {
+        return Err(solana_program::program_error::ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
 /// Errors if:
 /// - Owner is not Ore program.
 /// - Data is empty.
@@ -158,6 +191,109 @@ pub fn load_proof<'a, 'info>(
     Ok(())
 }
 
+/// Errors if:
+/// - Owner is not Ore program.
+/// - Data is empty.
+/// - Data cannot deserialize into a proof account.
+/// - Expected to be writable, but is not.
+pub fn load_any_proof<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let proof_data = info.data.borrow();
+    Proof::try_from_bytes(&proof_data)?;
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Ore program.
+/// - Data is empty.
+/// - Data cannot deserialize into a proof account.
+/// - Proof miner does not match the expected address.
+/// - Expected to be writable, but is not.
+pub fn load_proof_with_miner<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    miner: &Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let proof_data = info.data.borrow();
+    let proof = Proof::try_from_bytes(&proof_data)?;
+
+    if proof.miner.ne(&miner) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Errors if:
+/// - Owner is not Ore program.
+/// - Data is empty.
+/// - Data cannot deserialize into a vesting account.
+/// - Address does not match the vesting PDA of its proof.
+/// - Vesting authority does not match the expected address.
+/// - Expected to be writable, but is not.
+pub fn load_vesting<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    authority: &Pubkey,
+    is_writable: bool,
+) -> Result<(), ProgramError> {
+    if info.owner.ne(&crate::id()) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if info.data_is_empty() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let vesting_data = info.data.borrow();
+    let vesting = Vesting::try_from_bytes(&vesting_data)?;
+
+    let pda = Pubkey::create_program_address(
+        &[VESTING, vesting.proof.as_ref(), &[vesting.bump as u8]],
+        &crate::id(),
+    )
+    .or(Err(ProgramError::InvalidSeeds))?;
+    if info.key.ne(&pda) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if vesting.authority.ne(&authority) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if is_writable && !info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
 /// Errors if:
 /// - Owner is not Ore program.
 /// - Address does not match the expected address.
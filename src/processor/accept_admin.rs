@@ -0,0 +1,39 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{loaders::*, state::Config, utils::AccountDeserialize};
+
+/// AcceptAdmin completes a handover of the program's admin account. Its responsibilities include:
+/// 1. Update the admin address to the pending admin.
+/// 2. Clear the pending admin address.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the pending admin.
+/// - Can only succeed if the provided config is valid.
+pub fn process_accept_admin<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    _data: &[u8],
+) -> ProgramResult {
+    // Load accounts
+    let [signer, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+
+    // Validate signer is pending admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.pending_admin.eq(&Pubkey::default()) || config.pending_admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Update admin
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    Ok(())
+}
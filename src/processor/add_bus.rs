@@ -0,0 +1,82 @@
+use std::mem::size_of;
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    error::OreError,
+    instruction::AddBusArgs,
+    loaders::*,
+    state::{Bus, Config},
+    utils::AccountDeserialize,
+    utils::{create_pda, Discriminator},
+    BUS, MAX_BUS_COUNT,
+};
+
+/// AddBus creates an additional bus account for parallelizing mine operations. Its responsibilities include:
+/// 1. Initialize a new bus account with the next available id.
+/// 2. Increment the bus count.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the program admin.
+/// - Can only succeed if the bus count is below the maximum bus count.
+/// - Can only succeed if the provided bus account PDA is valid (associated with the next bus id).
+/// - The provided config and system program must be valid.
+///
+/// Discussion:
+/// - The new bus starts with no rewards and is marked as topped up for the current epoch, so it is only
+///   funded once the next epoch begins. Reset does not count it towards the ending epoch's rewards.
+pub fn process_add_bus<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = AddBusArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, bus_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+    load_program(system_program, system_program::id())?;
+
+    // Validate signer is admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate bus count
+    let id = config.bus_count;
+    if id.ge(&(MAX_BUS_COUNT as u64)) {
+        return Err(OreError::MaxBusCount.into());
+    }
+    load_uninitialized_pda(bus_info, &[BUS, &[id as u8]], args.bump, &crate::id())?;
+
+    // Initialize bus
+    create_pda(
+        bus_info,
+        &crate::id(),
+        8 + size_of::<Bus>(),
+        &[BUS, &[id as u8], &[args.bump]],
+        system_program,
+        signer,
+    )?;
+    let mut bus_data = bus_info.try_borrow_mut_data()?;
+    bus_data[0] = Bus::discriminator() as u8;
+    let bus = Bus::try_from_bytes_mut(&mut bus_data)?;
+    bus.id = id;
+    bus.epoch = config.epoch;
+    bus.rewards = 0;
+    bus.theoretical_rewards = 0;
+
+    // Update bus count
+    config.bus_count = id.saturating_add(1);
+
+    Ok(())
+}
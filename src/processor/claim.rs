@@ -1,21 +1,36 @@
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey, sysvar::Sysvar,
 };
+use spl_token::state::Mint;
 
 use crate::{
-    error::OreError, instruction::ClaimArgs, loaders::*, state::Proof, utils::AccountDeserialize,
-    MINT_ADDRESS, ONE_DAY, TREASURY, TREASURY_BUMP,
+    error::OreError,
+    instruction::ClaimArgs,
+    is_supply_converged,
+    loaders::*,
+    state::{Config, Proof, Treasury},
+    utils::AccountDeserialize,
+    MINT_ADDRESS, ONE_DAY, PAUSE_CLAIM, TREASURY, TREASURY_BUMP,
 };
 
-/// Claim distributes Ore from the treasury to a miner. Its responsibilies include:
+/// Claim distributes mined Ore from the treasury to a miner. Its responsibilies include:
 /// 1. Decrement the miner's claimable balance.
 /// 2. Transfer tokens from the treasury to the miner.
+/// 3. Burn the early claim penalty, or return it to the treasury reward pool once emissions have tapered off.
 ///
 /// Safety requirements:
-/// - Claim is a permissionless instruction and can be called by any user.
+/// - Can only succeed if claims are not paused.
+/// - Can only succeed if the signer is the proof authority.
 /// - Can only succeed if the claimed amount is less than or equal to the miner's claimable rewards.
-/// - The provided beneficiary, token account, treasury, treasury token account, and token program must be valid.
+/// - The provided beneficiary, config, mint, proof, treasury, treasury token account, and token program must be valid.
+///
+/// Discussion:
+/// - Once the supply has converged to the max supply, no more tokens are minted and mining is funded by the
+///   treasury reward pool instead. From then on, early claim penalties are kept in the treasury and added to
+///   the reward pool rather than burned, so the pool is continuously replenished.
+/// - Miners who would rather not pay the early claim penalty can use the vest instruction instead, which
+///   releases their rewards linearly over the vesting duration without burning any of them.
 pub fn process_claim<'a, 'info>(
     _program_id: &Pubkey,
     accounts: &'a [AccountInfo<'info>],
@@ -26,15 +41,17 @@ pub fn process_claim<'a, 'info>(
     let amount = u64::from_le_bytes(args.amount);
 
     // Load accounts
-    let [signer, beneficiary_info, mint_info, proof_info, treasury_info, treasury_tokens_info, token_program] =
+    let [signer, beneficiary_info, config_info, mint_info, proof_info, treasury_info, treasury_tokens_info, token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     load_signer(signer)?;
     load_token_account(beneficiary_info, None, &MINT_ADDRESS, true)?;
+    load_config(config_info, false)?;
     load_mint(mint_info, MINT_ADDRESS, true)?;
-    load_treasury(treasury_info, false)?;
+    load_proof(proof_info, signer.key, true)?;
+    load_treasury(treasury_info, true)?;
     load_token_account(
         treasury_tokens_info,
         Some(treasury_info.key),
@@ -43,6 +60,13 @@ pub fn process_claim<'a, 'info>(
     )?;
     load_program(token_program, spl_token::id())?;
 
+    // Validate claims are not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & PAUSE_CLAIM).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
     // If last claim was less than 1 day ago, burn some of the claim amount
     let mut claim_amount = amount;
     let mut proof_data = proof_info.data.borrow_mut();
@@ -55,24 +79,35 @@ pub fn process_claim<'a, 'info>(
             .saturating_mul(t.saturating_sub(clock.unix_timestamp) as u64)
             .saturating_div(ONE_DAY as u64);
 
-        // Burn tokens from treasury
-        solana_program::program::invoke_signed(
-            &spl_token::instruction::burn(
-                &spl_token::id(),
-                treasury_tokens_info.key,
-                mint_info.key,
-                treasury_info.key,
-                &[treasury_info.key],
-                burn_amount,
-            )?,
-            &[
-                token_program.clone(),
-                treasury_tokens_info.clone(),
-                mint_info.clone(),
-                treasury_info.clone(),
-            ],
-            &[&[TREASURY, &[TREASURY_BUMP]]],
-        )?;
+        // Return tokens to the reward pool if emissions have tapered off, otherwise burn them
+        let mint = Mint::unpack(&mint_info.data.borrow()).expect("Failed to parse mint");
+        if is_supply_converged(mint.supply) {
+            let mut treasury_data = treasury_info.data.borrow_mut();
+            let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+            treasury.reward_pool = treasury.reward_pool.saturating_add(burn_amount);
+        } else {
+            let mut treasury_data = treasury_info.data.borrow_mut();
+            let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+            treasury.total_burned = treasury.total_burned.saturating_add(burn_amount);
+            drop(treasury_data);
+            solana_program::program::invoke_signed(
+                &spl_token::instruction::burn(
+                    &spl_token::id(),
+                    treasury_tokens_info.key,
+                    mint_info.key,
+                    treasury_info.key,
+                    &[treasury_info.key],
+                    burn_amount,
+                )?,
+                &[
+                    token_program.clone(),
+                    treasury_tokens_info.clone(),
+                    mint_info.clone(),
+                    treasury_info.clone(),
+                ],
+                &[&[TREASURY, &[TREASURY_BUMP]]],
+            )?;
+        }
 
         // Update claim amount
         claim_amount = amount.saturating_sub(burn_amount);
@@ -87,6 +122,12 @@ pub fn process_claim<'a, 'info>(
     // Update timestamp
     proof.last_claim_at = clock.unix_timestamp;
 
+    // Update lifetime claims
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.total_claimed = treasury.total_claimed.saturating_add(claim_amount);
+    drop(treasury_data);
+
     // Distribute tokens from treasury to beneficiary
     solana_program::program::invoke_signed(
         &spl_token::instruction::transfer(
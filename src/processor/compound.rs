@@ -0,0 +1,89 @@
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    error::OreError,
+    instruction::CompoundArgs,
+    loaders::*,
+    state::{Config, Proof, Treasury},
+    utils::{calculate_stake_weighted_at, AccountDeserialize},
+    PAUSE_CLAIM, PAUSE_STAKE,
+};
+
+/// Compound moves a miner's claimable rewards into their staked balance. Its responsibilities include:
+/// 1. Decrement the miner's claimable balance.
+/// 2. Increment the miner's staked balance.
+/// 3. Blend the deposit into the age of the miner's stake.
+///
+/// Safety requirements:
+/// - Can only succeed if neither claims nor staking are paused.
+/// - Can only succeed if the signer is the proof authority.
+/// - Can only succeed if the amount is less than or equal to the miner's claimable rewards.
+/// - The provided config, proof, and treasury must be valid.
+///
+/// Discussion:
+/// - Claimable rewards are already held by the treasury, so compounding requires no token transfers. It is
+///   equivalent to a claim immediately followed by a stake deposit, except that it is atomic and the early
+///   claim penalty is never applied.
+/// - Compounded rewards are unlocked stake. Like any other deposit, they restart the unstake cooldown, so
+///   compounding cannot be used to withdraw rewards any sooner than an unpenalized claim.
+/// - The compounded amount is recorded in the treasury as both claimed and staked, which keeps the
+///   lifetime token flows consistent with a separate claim and stake.
+pub fn process_compound<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = CompoundArgs::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts
+    let [signer, config_info, proof_info, treasury_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, false)?;
+    load_proof(proof_info, signer.key, true)?;
+    load_treasury(treasury_info, true)?;
+
+    // Validate claims and staking are not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & (PAUSE_CLAIM | PAUSE_STAKE)).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
+    // Update miner balance
+    let mut proof_data = proof_info.data.borrow_mut();
+    let proof = Proof::try_from_bytes_mut(&mut proof_data)?;
+    proof.balance = proof
+        .balance
+        .checked_sub(amount)
+        .ok_or(OreError::ClaimTooLarge)?;
+
+    // Update stake age
+    let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
+    proof.stake_weighted_at = calculate_stake_weighted_at(
+        proof.stake,
+        proof.stake_weighted_at,
+        amount,
+        clock.unix_timestamp,
+    );
+
+    // Update staked balance
+    proof.stake = proof.stake.saturating_add(amount);
+
+    // Update deposit timestamp
+    proof.last_stake_at = clock.unix_timestamp;
+
+    // Update lifetime claims and deposits
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.total_claimed = treasury.total_claimed.saturating_add(amount);
+    treasury.total_staked = treasury.total_staked.saturating_add(amount);
+
+    Ok(())
+}
@@ -26,10 +26,10 @@ pub fn process_deregister<'a, 'info>(
     load_proof(proof_info, signer.key, true)?;
     load_program(system_program, system_program::id())?;
 
-    // Validate balance is zero
+    // Validate balances are zero
     let proof_data = proof_info.data.borrow();
     let proof = Proof::try_from_bytes(&proof_data)?;
-    if proof.balance.gt(&0) {
+    if proof.balance.gt(&0) || proof.stake.gt(&0) {
         return Err(ProgramError::InvalidAccountData);
     }
     drop(proof_data);
@@ -13,24 +13,28 @@ use spl_token::state::Mint;
 use crate::{
     instruction::*,
     loaders::*,
-    state::{Bus, Config, Treasury},
+    state::{Bus, Config, EpochHistory, Treasury},
     utils::create_pda,
     utils::AccountDeserialize,
     utils::Discriminator,
-    BUS, BUS_COUNT, CONFIG, INITIAL_BASE_REWARD_RATE, INITIAL_TOLERANCE, METADATA, METADATA_NAME,
-    METADATA_SYMBOL, METADATA_URI, MINT, MINT_ADDRESS, MINT_NOISE, TOKEN_DECIMALS, TREASURY,
+    BUS, CONFIG, EPOCH_HISTORY, INITIALIZER_ADDRESS, INITIAL_BASE_REWARD_RATE, INITIAL_BUS_COUNT,
+    INITIAL_EPOCH_DURATION, INITIAL_MIN_DIFFICULTY, INITIAL_TOLERANCE, INITIAL_VESTING_DURATION,
+    METADATA, METADATA_NAME, METADATA_SYMBOL, METADATA_URI, MINT, MINT_ADDRESS, MINT_NOISE,
+    PAUSE_ALL, TOKEN_DECIMALS, TREASURY,
 };
 
 /// Initialize sets up the Ore program. Its responsibilities include:
-/// 1. Initialize the 8 bus accounts.
+/// 1. Initialize the initial 8 bus accounts.
 /// 2. Initialize the treasury account.
-/// 3. Initialize the Ore mint account.
-/// 4. Initialize the mint metadata account.
-/// 5. Initialize the treasury token account.
-/// 6. Set the signer as the program admin.
+/// 3. Initialize the epoch history account.
+/// 4. Initialize the Ore mint account.
+/// 5. Initialize the mint metadata account.
+/// 6. Initialize the treasury token account.
+/// 7. Set the signer as the program admin.
+/// 8. Pause all instructions until the admin explicitly unpauses them.
 ///
 /// Safety requirements:
-/// - Initialize is a permissionless instruction and can be called by anyone.
+/// - Can only succeed if the signer is the hardcoded initializer.
 /// - Can only succeed once for the entire lifetime of the program.
 /// - Can only succeed if all provided PDAs match their expected values.
 /// - Can only succeed if provided system program, token program,
@@ -39,6 +43,10 @@ use crate::{
 /// Discussion
 /// - The signer of this instruction is set as the program admin and the
 ///   upgrade authority of the mint metadata account.
+/// - Requiring a hardcoded signer prevents the deploy from being front-run by an attacker who
+///   would otherwise become the program admin.
+/// - The program starts fully paused so the admin can verify the deployment before opening it
+///   up with the pause instruction.
 pub fn process_initialize<'a, 'info>(
     _program_id: &Pubkey,
     accounts: &'a [AccountInfo<'info>],
@@ -48,12 +56,15 @@ pub fn process_initialize<'a, 'info>(
     let args = InitializeArgs::try_from_bytes(data)?;
 
     // Load accounts
-    let [signer, bus_0_info, bus_1_info, bus_2_info, bus_3_info, bus_4_info, bus_5_info, bus_6_info, bus_7_info, config_info, metadata_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, metadata_program, rent_sysvar] =
+    let [signer, bus_0_info, bus_1_info, bus_2_info, bus_3_info, bus_4_info, bus_5_info, bus_6_info, bus_7_info, config_info, epoch_history_info, metadata_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, metadata_program, rent_sysvar] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     load_signer(signer)?;
+    if signer.key.ne(&INITIALIZER_ADDRESS) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
     load_uninitialized_pda(bus_0_info, &[BUS, &[0]], args.bus_0_bump, &crate::id())?;
     load_uninitialized_pda(bus_1_info, &[BUS, &[1]], args.bus_1_bump, &crate::id())?;
     load_uninitialized_pda(bus_2_info, &[BUS, &[2]], args.bus_2_bump, &crate::id())?;
@@ -63,6 +74,12 @@ pub fn process_initialize<'a, 'info>(
     load_uninitialized_pda(bus_6_info, &[BUS, &[6]], args.bus_6_bump, &crate::id())?;
     load_uninitialized_pda(bus_7_info, &[BUS, &[7]], args.bus_7_bump, &crate::id())?;
     load_uninitialized_pda(config_info, &[CONFIG], args.config_bump, &crate::id())?;
+    load_uninitialized_pda(
+        epoch_history_info,
+        &[EPOCH_HISTORY],
+        args.epoch_history_bump,
+        &crate::id(),
+    )?;
     load_uninitialized_pda(
         metadata_info,
         &[
@@ -102,7 +119,7 @@ pub fn process_initialize<'a, 'info>(
         args.bus_6_bump,
         args.bus_7_bump,
     ];
-    for i in 0..BUS_COUNT {
+    for i in 0..INITIAL_BUS_COUNT {
         create_pda(
             bus_infos[i],
             &crate::id(),
@@ -115,6 +132,7 @@ pub fn process_initialize<'a, 'info>(
         bus_data[0] = Bus::discriminator() as u8;
         let bus = Bus::try_from_bytes_mut(&mut bus_data)?;
         bus.id = i as u64;
+        bus.epoch = 0;
         bus.rewards = 0;
     }
 
@@ -131,9 +149,23 @@ pub fn process_initialize<'a, 'info>(
     config_data[0] = Config::discriminator() as u8;
     let config = Config::try_from_bytes_mut(&mut config_data)?;
     config.admin = *signer.key;
+    config.pending_admin = Pubkey::default();
     config.base_reward_rate = INITIAL_BASE_REWARD_RATE;
+    config.rate_ema_window = 0;
+    config.theoretical_rewards_ema = 0;
+    config.bus_count = INITIAL_BUS_COUNT as u64;
+    config.funded_bus_count = 0;
+    config.epoch = 0;
+    config.epoch_duration = INITIAL_EPOCH_DURATION;
+    config.pending_epoch_duration = 0;
+    config.epoch_rewards = 0;
+    config.bus_rewards = 0;
+    config.pooled_rewards = 0;
     config.last_reset_at = 0;
-    config.paused = 0;
+    config.min_difficulty = INITIAL_MIN_DIFFICULTY as u64;
+    config.paused = PAUSE_ALL;
+    config.reset_bounty = 0;
+    config.vesting_duration = INITIAL_VESTING_DURATION;
     config.tolerance_liveness = INITIAL_TOLERANCE;
     config.tolerance_spam = INITIAL_TOLERANCE;
 
@@ -150,8 +182,30 @@ pub fn process_initialize<'a, 'info>(
     treasury_data[0] = Treasury::discriminator() as u8;
     let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
     treasury.bump = args.treasury_bump as u64;
+    treasury.reward_pool = 0;
+    treasury.total_minted = 0;
+    treasury.total_burned = 0;
+    treasury.total_claimed = 0;
+    treasury.total_staked = 0;
+    treasury.total_unstaked = 0;
+    treasury.total_upgraded = 0;
     drop(treasury_data);
 
+    // Initialize epoch history
+    create_pda(
+        epoch_history_info,
+        &crate::id(),
+        8 + size_of::<EpochHistory>(),
+        &[EPOCH_HISTORY, &[args.epoch_history_bump]],
+        system_program,
+        signer,
+    )?;
+    let mut epoch_history_data = epoch_history_info.data.borrow_mut();
+    epoch_history_data[0] = EpochHistory::discriminator() as u8;
+    let epoch_history = EpochHistory::try_from_bytes_mut(&mut epoch_history_data)?;
+    epoch_history.head = 0;
+    drop(epoch_history_data);
+
     // Initialize mint
     create_pda(
         mint_info,
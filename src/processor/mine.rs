@@ -7,36 +7,39 @@ use solana_program::{
     blake3::hashv,
     clock::Clock,
     entrypoint::ProgramResult,
+    program::set_return_data,
     program_error::ProgramError,
+    pubkey,
     pubkey::Pubkey,
     sanitize::SanitizeError,
     serialize_utils::{read_pubkey, read_u16, read_u8},
     slot_hashes::SlotHash,
     sysvar::{self, instructions::load_current_index, Sysvar},
 };
-use solana_program::{log::sol_log, pubkey};
 
 use crate::{
     error::OreError,
     instruction::{MineArgs, OreInstruction},
     loaders::*,
     state::{Bus, Config, Proof},
-    utils::AccountDeserialize,
-    EPOCH_DURATION, MIN_DIFFICULTY, ONE_MINUTE, ONE_YEAR,
+    utils::{calculate_reward, AccountDeserialize, MineEvent},
+    MINE_EVENT_VERSION, PAUSE_MINE,
 };
 
 /// Mine is the primary workhorse instruction of the Ore program. Its responsibilities include:
 /// 1. Calculate the hash from the provided nonce.
-/// 2. Payout rewards based on difficulty, staking multiplier, and liveness penalty.
-/// 3. Generate a new challenge for the miner.
-/// 4. Update the miner's lifetime stats.
+/// 2. Top up the bus, if it has not yet been topped up this epoch.
+/// 3. Payout rewards based on difficulty, staking multiplier, and liveness penalty.
+/// 4. Generate a new challenge for the miner.
+/// 5. Update the miner's lifetime stats.
+/// 6. Emit a mine event with the itemized reward breakdown.
 ///
 /// Safety requirements:
 /// - Mine is a permissionless instruction and can be called by any signer.
 /// - Can only succeed if mining is not paused.
-/// - Can only succeed if the last reset was less than 60 seconds ago.
+/// - Can only succeed if the last reset was less than one epoch duration ago.
 /// - Can only succeed if the provided hash satisfies the minimum difficulty requirement.
-/// - The the provided proof account must be associated with the signer.
+/// - The provided proof account must list the signer as its miner.
 /// - The provided bus, config, noise, stake, and slot hash sysvar must be valid.
 pub fn process_mine<'a, 'info>(
     _program_id: &Pubkey,
@@ -55,7 +58,7 @@ pub fn process_mine<'a, 'info>(
     load_signer(signer)?;
     load_any_bus(bus_info, true)?;
     load_config(config_info, false)?;
-    load_proof(proof_info, signer.key, true)?;
+    load_proof_with_miner(proof_info, signer.key, true)?;
     load_sysvar(instructions_sysvar, sysvar::instructions::id())?;
     load_sysvar(slot_hashes_sysvar, sysvar::slot_hashes::id())?;
 
@@ -67,7 +70,7 @@ pub fn process_mine<'a, 'info>(
     // Validate mining is not paused
     let config_data = config_info.data.borrow();
     let config = Config::try_from_bytes(&config_data)?;
-    if config.paused.ne(&0) {
+    if (config.paused & PAUSE_MINE).ne(&0) {
         return Err(OreError::IsPaused.into());
     }
 
@@ -83,7 +86,7 @@ pub fn process_mine<'a, 'info>(
     // Validate epoch is active
     if config
         .last_reset_at
-        .saturating_add(EPOCH_DURATION)
+        .saturating_add(config.epoch_duration)
         .le(&clock.unix_timestamp)
     {
         return Err(OreError::NeedsReset.into());
@@ -98,75 +101,32 @@ pub fn process_mine<'a, 'info>(
     // Validate hash satisfies the minimnum difficulty
     let hash = solution.to_hash();
     let difficulty = hash.difficulty();
-    sol_log(&format!("Diff {}", difficulty));
-    if difficulty.lt(&MIN_DIFFICULTY) {
+    let min_difficulty = config.min_difficulty as u32;
+    if difficulty.lt(&min_difficulty) {
         return Err(OreError::HashTooEasy.into());
     }
 
-    // Calculate base reward rate
-    let difficulty = difficulty.saturating_sub(MIN_DIFFICULTY);
-    let mut reward = config
-        .base_reward_rate
-        .saturating_mul(2u64.saturating_pow(difficulty));
-    sol_log(&format!("Base {}", reward));
-
-    // Apply staking multiplier.
-    // The multiplier can range 1x to 2x. To receive the maximum multiplier, the stake balance must be
-    // greater than or equal to two years worth of rewards at the selected difficulty. Miners are only
-    // eligable for a multipler if their last stake deposit was more than one minute ago.
-    if proof
-        .last_stake_at
-        .saturating_add(ONE_MINUTE)
-        .le(&clock.unix_timestamp)
-    {
-        let upper_bound = reward.saturating_mul(ONE_YEAR);
-        let staking_reward = proof
-            .balance
-            .min(upper_bound)
-            .saturating_mul(reward)
-            .saturating_div(upper_bound);
-        reward = reward.saturating_add(staking_reward);
-        sol_log(&format!("Staking {}", staking_reward));
-    };
-
-    // Apply spam penalty
-    let t = clock.unix_timestamp;
-    let t_target = proof.last_hash_at.saturating_add(ONE_MINUTE);
-    let t_spam = t_target.saturating_sub(config.tolerance_spam);
-    if t.lt(&t_spam) {
-        reward = 0;
-        sol_log("Spam penalty");
-    }
-
-    // Apply liveness penalty
-    let t_liveness = t_target.saturating_add(config.tolerance_liveness);
-    if t.gt(&t_liveness) {
-        reward = reward.saturating_sub(
-            reward
-                .saturating_mul(t.saturating_sub(t_liveness) as u64)
-                .saturating_div(ONE_MINUTE as u64),
-        );
-        sol_log(&format!(
-            "Liveness penalty ({} sec) {}",
-            t.saturating_sub(t_liveness),
-            reward,
-        ));
-    }
-
-    // Limit payout amount to whatever is left in the bus
+    // Top up the bus if this is its first hash of the epoch
     let mut bus_data = bus_info.data.borrow_mut();
     let bus = Bus::try_from_bytes_mut(&mut bus_data)?;
-    let reward_actual = reward.min(bus.rewards);
+    if bus.epoch.lt(&config.epoch) {
+        bus.epoch = config.epoch;
+        bus.rewards = config.bus_rewards;
+        bus.theoretical_rewards = 0;
+    }
+
+    // Calculate rewards
+    let quote = calculate_reward(config, proof, bus, difficulty, clock.unix_timestamp);
 
     // Update balances
-    sol_log(&format!("Total {}", reward));
-    sol_log(&format!("Bus {}", bus.rewards));
-    bus.theoretical_rewards = bus.theoretical_rewards.saturating_add(reward);
+    bus.theoretical_rewards = bus
+        .theoretical_rewards
+        .saturating_add(quote.reward_theoretical);
     bus.rewards = bus
         .rewards
-        .checked_sub(reward_actual)
+        .checked_sub(quote.reward)
         .expect("This should not happen");
-    proof.balance = proof.balance.saturating_add(reward_actual);
+    proof.balance = proof.balance.saturating_add(quote.reward);
 
     // Hash recent slot hash into the next challenge to prevent pre-mining attacks
     proof.challenge = hashv(&[
@@ -180,14 +140,24 @@ pub fn process_mine<'a, 'info>(
 
     // Update lifetime stats
     proof.total_hashes = proof.total_hashes.saturating_add(1);
-    proof.total_rewards = proof.total_rewards.saturating_add(reward);
+    proof.total_rewards = proof.total_rewards.saturating_add(quote.reward_theoretical);
 
     // Log the mined rewards
-    // set_return_data(bytemuck::bytes_of(&MineEvent {
-    //     difficulty: difficulty as u64,
-    //     reward,
-    //     timing: t.saturating_sub(t_target),
-    // }));
+    set_return_data(
+        MineEvent {
+            version: MINE_EVENT_VERSION,
+            epoch: config.epoch,
+            difficulty: difficulty as u64,
+            reward_base: quote.reward_base,
+            reward_staking: quote.reward_staking,
+            penalty_spam: quote.penalty_spam,
+            penalty_liveness: quote.penalty_liveness,
+            bus: bus.id,
+            reward: quote.reward,
+            reward_theoretical: quote.reward_theoretical,
+        }
+        .to_bytes(),
+    );
 
     Ok(())
 }
@@ -1,4 +1,7 @@
+mod accept_admin;
+mod add_bus;
 mod claim;
+mod compound;
 mod deregister;
 mod initialize;
 mod mine;
@@ -6,11 +9,23 @@ mod pause;
 mod register;
 mod reset;
 mod stake;
+mod unstake;
 mod update_admin;
+mod update_epoch_duration;
+mod update_min_difficulty;
+mod update_miner;
+mod update_rate_ema_window;
+mod update_reset_bounty;
 mod update_tolerance;
+mod update_vesting_duration;
 mod upgrade;
+mod vest;
+mod withdraw;
 
+pub use accept_admin::*;
+pub use add_bus::*;
 pub use claim::*;
+pub use compound::*;
 pub use deregister::*;
 pub use initialize::*;
 pub use mine::*;
@@ -18,6 +33,15 @@ pub use pause::*;
 pub use register::*;
 pub use reset::*;
 pub use stake::*;
+pub use unstake::*;
 pub use update_admin::*;
+pub use update_epoch_duration::*;
+pub use update_min_difficulty::*;
+pub use update_miner::*;
+pub use update_rate_ema_window::*;
+pub use update_reset_bounty::*;
 pub use update_tolerance::*;
+pub use update_vesting_duration::*;
 pub use upgrade::*;
+pub use vest::*;
+pub use withdraw::*;
@@ -3,18 +3,23 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::{instruction::PauseArgs, loaders::*, state::Config, utils::AccountDeserialize};
+use crate::{
+    instruction::PauseArgs, loaders::*, state::Config, utils::AccountDeserialize, PAUSE_ALL,
+};
 
-/// Pause updates the program's pause flag. Its responsibilities include:
-/// 1. Update the pause flag.
+/// Pause updates the program's pause flags. Its responsibilities include:
+/// 1. Update the pause flags.
 ///
 /// Safety requirements:
 /// - Can only succeed if the signer is the program admin.
 /// - Can only succeed if the provided config is valid.
+/// - Can only succeed if the provided flags are all known `PAUSE_*` flags.
 ///
 /// Discussion:
 /// - This should only be used to address critical contract risks and force migration to a new
 ///   verison (hardfork).
+/// - Each flag halts an independent flow, so for example upgrades can be frozen while miners keep
+///   working. Passing zero unpauses everything.
 pub fn process_pause<'a, 'info>(
     _program_id: &Pubkey,
     accounts: &'a [AccountInfo<'info>],
@@ -37,8 +42,13 @@ pub fn process_pause<'a, 'info>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Validate flags
+    if (args.paused & !PAUSE_ALL).ne(&0) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
     // Update paused
-    config.paused = args.paused as u64;
+    config.paused = args.paused;
 
     Ok(())
 }
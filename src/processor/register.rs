@@ -66,7 +66,9 @@ pub fn process_register<'a, 'info>(
     proof_data[0] = Proof::discriminator() as u8;
     let proof = Proof::try_from_bytes_mut(&mut proof_data)?;
     proof.authority = *signer.key;
+    proof.miner = *signer.key;
     proof.balance = 0;
+    proof.stake = 0;
     proof.challenge = hashv(&[
         signer.key.as_ref(),
         &slot_hashes_info.data.borrow()[0..size_of::<SlotHash>()],
@@ -75,6 +77,10 @@ pub fn process_register<'a, 'info>(
     proof.last_claim_at = clock.unix_timestamp;
     proof.last_hash_at = clock.unix_timestamp;
     proof.last_stake_at = clock.unix_timestamp;
+    proof.stake_weighted_at = clock.unix_timestamp;
+    proof.locked_stake = 0;
+    proof.lock_tier = 0;
+    proof.lock_expires_at = 0;
     proof.total_hashes = 0;
     proof.total_rewards = 0;
 
@@ -5,59 +5,91 @@ use solana_program::{
 use spl_token::state::Mint;
 
 use crate::{
+    bus_epoch_rewards,
     error::OreError,
     loaders::{
-        load_bus, load_config, load_mint, load_program, load_signer, load_token_account,
-        load_treasury,
+        load_bus, load_config, load_epoch_history, load_mint, load_program, load_signer,
+        load_token_account, load_treasury,
     },
-    state::{Bus, Config},
+    max_epoch_rewards,
+    state::{Bus, Config, EpochHistory, EpochRecord, Treasury},
+    target_epoch_rewards,
     utils::AccountDeserialize,
-    BUS_COUNT, BUS_EPOCH_REWARDS, EPOCH_DURATION, MAX_EPOCH_REWARDS, MAX_SUPPLY, MINT_ADDRESS,
-    SMOOTHING_FACTOR, TARGET_EPOCH_REWARDS, TREASURY, TREASURY_BUMP,
+    MAX_BUS_COUNT, MAX_CATCH_UP_EPOCHS, MAX_REWARDS_PER_MINUTE, MAX_SUPPLY, MINT_ADDRESS,
+    MIN_EPOCH_DURATION, PAUSE_RESET, SMOOTHING_FACTOR, TARGET_REWARDS_PER_MINUTE, TREASURY,
+    TREASURY_BUMP,
 };
 
-// TODO Update comments to account for 5 minute epoch
-
 /// Reset sets up the Ore program for the next epoch. Its responsibilities include:
-/// 1. Reset bus account rewards counters.
-/// 2. Adjust the reward rate to stabilize inflation.
-/// 3. Top up the treasury token account to fund claims.
+/// 1. Advance the epoch counter by the number of epochs elapsed since the last reset.
+/// 2. Measure the rewards paid out by every bus account, and set the bus allocation for the next epoch.
+/// 3. Adjust the reward rate to stabilize inflation.
+/// 4. Top up the treasury token account to fund claims.
+/// 5. Pay the reset bounty to the beneficiary.
+/// 6. Record the ended epoch in the epoch history.
+/// 7. Taper the rewards of the next epoch according to the remaining supply, or reserve them from the
+///    treasury reward pool once emissions have tapered off.
 ///
 /// Safety requirements:
 /// - Reset is a permissionless instruction and can be invoked by any signer.
-/// - Can only succeed if START_AT has passed.
-/// - Can only succeed if more tha 60 seconds or more have passed since the last successful reset.
-/// - The busses, mint, treasury, treasury token account, and token program must all be valid.
+/// - Can only succeed if reset is not paused.
+/// - Can only succeed if at least one epoch duration has passed since the last successful reset.
+/// - The beneficiary, config, epoch history, mint, treasury, treasury token account, and token program must all be valid.
+/// - Every bus account must be provided, in order of id, after the fixed accounts.
 ///
 /// Discussion:
-/// - It is important that `reset` can only be invoked once per 60 second period to ensure the supply growth rate
-///   stays within the guaranteed bounds of 0 ≤ R ≤ 2 ORE/min.
+/// - Reset never writes to the busses. Each bus is topped up lazily by the first mine operation
+///   to touch it in the new epoch. Reset still reads every bus to measure the rewards paid out in the
+///   ending epoch, since that measurement drives both the reward rate update and the treasury top up.
+///   A read lock conflicts with the write locks taken by mine, so reset still contends with miners for
+///   the bus accounts while it executes.
+/// - It is important that `reset` can only be invoked once per epoch to ensure the supply growth rate
+///   stays within the guaranteed bounds of 0 ≤ R ≤ 5 ORE/min.
+/// - The epoch duration is configured by the admin. The target and maximum rewards per epoch scale
+///   with it, so the supply growth bounds per minute hold regardless of the epoch duration.
 /// - The reward rate is dynamically adjusted based on last epoch's theoretical reward rate to target an average
 ///   supply growth rate of 1 ORE/min.
+/// - A new epoch duration set by the admin is only applied here, when the next allocation is computed, so an
+///   allocation can never be mined in an epoch shorter than the one it was sized for. The theoretical rewards
+///   of the ended epoch are scaled to the new duration before the reward rate is adjusted.
+/// - If the admin has configured an EMA window, the reward rate is adjusted based on an exponential moving
+///   average of the theoretical rewards rather than the last epoch alone, which dampens the rate when miners
+///   come and go. The average is rescaled whenever the rate changes, so it always reflects the rewards that
+///   the recent hashpower would earn at the current rate.
 /// - The "theoretical" reward rate refers to the amount that would have been paid out if rewards were not capped by
 ///   the bus limits. It's necessary to use this value to ensure the reward rate update calculation accurately
 ///   accounts for the difficulty of submitted hashes.
+/// - No hashes can be submitted in skipped epochs, so the rate is adjusted once for every elapsed epoch as if
+///   each repeated the demand of the last active epoch. This lets the rate move by more than the smoothing
+///   factor after a long pause.
+/// - Each reset which ends an epoch appends the epoch's reward rate, allocation, mined rewards and theoretical
+///   rewards to the epoch history, so recent emissions and demand can be read on chain. Skipped epochs are not
+///   recorded, since nothing was mined in them.
+/// - The reset bounty is paid out of the rewards that went unmined in the last epoch, so it never
+///   pushes supply growth above the per-epoch maximum. If miners exhausted the busses, the bounty
+///   is reduced accordingly. The bounty is only paid when the epoch actually advances.
+/// - Emissions taper asymptotically as the supply approaches the max supply. The bus allocations
+///   and the target rewards are scaled by the fraction of the max supply which remains to be mined,
+///   so the supply converges to the cap and reset never needs to fail once it is approached.
+/// - Once emissions have tapered off completely, reset keeps advancing epochs in a terminal mode. Nothing
+///   is minted anymore. Instead, the bus allocations are reserved from the treasury reward pool, which is
+///   refilled by early claim penalties, and whatever goes unmined is returned to the pool at the next reset.
+///   The reset bounty is paid out of the pool as well.
 pub fn process_reset<'a, 'info>(
     _program_id: &Pubkey,
     accounts: &'a [AccountInfo<'info>],
     _data: &[u8],
 ) -> ProgramResult {
     // Load accounts
-    let [signer, bus_0_info, bus_1_info, bus_2_info, bus_3_info, bus_4_info, bus_5_info, bus_6_info, bus_7_info, config_info, mint_info, treasury_info, treasury_tokens_info, token_program] =
+    let [signer, beneficiary_info, config_info, epoch_history_info, mint_info, treasury_info, treasury_tokens_info, token_program, bus_infos @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     load_signer(signer)?;
-    load_bus(bus_0_info, 0, true)?;
-    load_bus(bus_1_info, 1, true)?;
-    load_bus(bus_2_info, 2, true)?;
-    load_bus(bus_3_info, 3, true)?;
-    load_bus(bus_4_info, 4, true)?;
-    load_bus(bus_5_info, 5, true)?;
-    load_bus(bus_6_info, 6, true)?;
-    load_bus(bus_7_info, 7, true)?;
+    load_token_account(beneficiary_info, None, &MINT_ADDRESS, true)?;
     load_config(config_info, true)?;
+    load_epoch_history(epoch_history_info, true)?;
     load_mint(mint_info, MINT_ADDRESS, true)?;
     load_treasury(treasury_info, true)?;
     load_token_account(
@@ -67,64 +99,188 @@ pub fn process_reset<'a, 'info>(
         true,
     )?;
     load_program(token_program, spl_token::id())?;
-    let busses: [&AccountInfo; BUS_COUNT] = [
-        bus_0_info, bus_1_info, bus_2_info, bus_3_info, bus_4_info, bus_5_info, bus_6_info,
-        bus_7_info,
-    ];
 
-    // Validate mining is not paused
+    // Validate every bus is provided, in order
     let mut config_data = config_info.data.borrow_mut();
     let config = Config::try_from_bytes_mut(&mut config_data)?;
-    if config.paused.ne(&0) {
+    if (bus_infos.len() as u64).lt(&config.bus_count) {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let bus_infos = &bus_infos[..config.bus_count as usize];
+    for (i, bus_info) in bus_infos.iter().enumerate() {
+        load_bus(bus_info, i as u64, false)?;
+    }
+
+    // Validate reset is not paused
+    if (config.paused & PAUSE_RESET).ne(&0) {
         return Err(OreError::IsPaused.into());
     }
 
     // Validate enough time has passed since last reset
     let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
-    if config
-        .last_reset_at
-        .saturating_add(EPOCH_DURATION)
-        .gt(&clock.unix_timestamp)
-    {
+    let epochs_elapsed = calculate_epochs_elapsed(
+        config.last_reset_at,
+        clock.unix_timestamp,
+        config.epoch_duration,
+    );
+    if epochs_elapsed.eq(&0) {
         return Ok(());
     }
 
-    // Update reset timestamp
-    config.last_reset_at = clock.unix_timestamp;
-
-    // Reset bus accounts and calculate actual rewards mined since last reset
-    let mut total_remaining_rewards = 0u64;
+    // Calculate actual rewards mined since last reset. Busses which were not topped up in the
+    // ending epoch, or were added after it started, did not pay out any rewards.
+    let mut total_epoch_rewards = 0u64;
     let mut total_theoretical_rewards = 0u64;
-    for i in 0..BUS_COUNT {
-        let mut bus_data = busses[i].data.borrow_mut();
-        let bus = Bus::try_from_bytes_mut(&mut bus_data)?;
-        total_remaining_rewards = total_remaining_rewards.saturating_add(bus.rewards);
-        total_theoretical_rewards =
-            total_theoretical_rewards.saturating_add(bus.theoretical_rewards);
-        bus.rewards = BUS_EPOCH_REWARDS;
-        bus.theoretical_rewards = 0;
-    }
-    let total_epoch_rewards = MAX_EPOCH_REWARDS.saturating_sub(total_remaining_rewards);
-
-    // Update base reward rate for next epoch
-    config.base_reward_rate =
-        calculate_new_reward_rate(config.base_reward_rate, total_theoretical_rewards);
-
-    // Max supply check
-    let mint = Mint::unpack(&mint_info.data.borrow()).expect("Failed to parse mint");
-    if mint.supply.ge(&MAX_SUPPLY) {
-        return Err(OreError::MaxSupply.into());
+    for bus_info in bus_infos.iter().take(config.funded_bus_count as usize) {
+        let bus_data = bus_info.data.borrow();
+        let bus = Bus::try_from_bytes(&bus_data)?;
+        if bus.epoch.eq(&config.epoch) {
+            total_epoch_rewards =
+                total_epoch_rewards.saturating_add(config.bus_rewards.saturating_sub(bus.rewards));
+            total_theoretical_rewards =
+                total_theoretical_rewards.saturating_add(bus.theoretical_rewards);
+        }
     }
+    let total_remaining_rewards = config.epoch_rewards.saturating_sub(total_epoch_rewards);
 
-    // Fund treasury token account
+    // Return the unmined portion of any rewards reserved from the reward pool
+    let treasury_data = treasury_info.data.borrow();
+    let mut reward_pool = Treasury::try_from_bytes(&treasury_data)?.reward_pool;
+    drop(treasury_data);
+    let pooled_epoch_rewards = total_epoch_rewards.min(config.pooled_rewards);
+    reward_pool =
+        reward_pool.saturating_add(config.pooled_rewards.saturating_sub(pooled_epoch_rewards));
+
+    // Fund treasury token account with the rewards that were not already reserved from the pool
+    let mint = Mint::unpack(&mint_info.data.borrow()).expect("Failed to parse mint");
     let amount = MAX_SUPPLY
         .saturating_sub(mint.supply)
-        .min(total_epoch_rewards);
+        .min(total_epoch_rewards.saturating_sub(pooled_epoch_rewards));
+    if amount.gt(&0) {
+        mint_rewards(
+            mint_info,
+            treasury_tokens_info,
+            treasury_info,
+            token_program,
+            amount,
+        )?;
+    }
+
+    // Pay reset bounty out of the epoch's unmined rewards
+    let mut supply = mint.supply.saturating_add(amount);
+    let bounty = config.reset_bounty.min(total_remaining_rewards);
+    if config.pooled_rewards.gt(&0) {
+        let bounty = bounty.min(reward_pool);
+        if bounty.gt(&0) {
+            reward_pool = reward_pool.saturating_sub(bounty);
+            transfer_rewards(
+                treasury_tokens_info,
+                beneficiary_info,
+                treasury_info,
+                token_program,
+                bounty,
+            )?;
+        }
+    } else {
+        let bounty = bounty.min(MAX_SUPPLY.saturating_sub(supply));
+        if bounty.gt(&0) {
+            supply = supply.saturating_add(bounty);
+            mint_rewards(
+                mint_info,
+                beneficiary_info,
+                treasury_info,
+                token_program,
+                bounty,
+            )?;
+        }
+    }
+
+    // Record the ended epoch. The first reset after initialization does not end an epoch.
+    if config.last_reset_at.gt(&0) {
+        let mut epoch_history_data = epoch_history_info.data.borrow_mut();
+        let epoch_history = EpochHistory::try_from_bytes_mut(&mut epoch_history_data)?;
+        epoch_history.push(EpochRecord {
+            epoch: config.epoch,
+            reset_at: clock.unix_timestamp,
+            base_reward_rate: config.base_reward_rate,
+            epoch_rewards: config.epoch_rewards,
+            rewards: total_epoch_rewards,
+            theoretical_rewards: total_theoretical_rewards,
+        });
+    }
+
+    // Apply any pending epoch duration to the next epoch. The theoretical rewards of the ended epoch
+    // are expressed per new epoch, and the moving average, which is denominated per epoch, is reseeded.
+    let mut theoretical_rewards = total_theoretical_rewards;
+    if config.pending_epoch_duration.gt(&0) {
+        theoretical_rewards = (theoretical_rewards as u128)
+            .saturating_mul(config.pending_epoch_duration as u128)
+            .saturating_div(config.epoch_duration.max(1) as u128)
+            as u64;
+        config.epoch_duration = config.pending_epoch_duration;
+        config.pending_epoch_duration = 0;
+        config.theoretical_rewards_ema = 0;
+    }
+
+    // Update reset timestamp, epoch counter, and bus allocations for the next epoch. Once
+    // emissions have tapered off, the busses are funded by reserving rewards from the pool.
+    config.last_reset_at = clock.unix_timestamp;
+    config.epoch = config.epoch.saturating_add(epochs_elapsed);
+    config.funded_bus_count = config.bus_count;
+    let target_rewards = if is_supply_converged(supply) {
+        let pooled_rewards = reward_pool.min(max_epoch_rewards(config.epoch_duration));
+        config.bus_rewards = pooled_rewards.saturating_div(config.bus_count.max(1));
+        config.epoch_rewards = config.bus_rewards.saturating_mul(config.bus_count);
+        config.pooled_rewards = config.epoch_rewards;
+        reward_pool = reward_pool.saturating_sub(config.pooled_rewards);
+        (config.epoch_rewards as u128)
+            .saturating_mul(TARGET_REWARDS_PER_MINUTE as u128)
+            .saturating_div(MAX_REWARDS_PER_MINUTE as u128) as u64
+    } else {
+        config.bus_rewards = calculate_tapered_rewards(
+            bus_epoch_rewards(config.epoch_duration, config.bus_count),
+            supply,
+        );
+        config.epoch_rewards = config.bus_rewards.saturating_mul(config.bus_count);
+        config.pooled_rewards = 0;
+        calculate_tapered_rewards(target_epoch_rewards(config.epoch_duration), supply)
+    };
+
+    // Update treasury reward pool and lifetime mints
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.reward_pool = reward_pool;
+    treasury.total_minted = treasury
+        .total_minted
+        .saturating_add(supply.saturating_sub(mint.supply));
+
+    // Update base reward rate for next epoch, catching up on any skipped epochs
+    (config.base_reward_rate, config.theoretical_rewards_ema) = calculate_caught_up_reward_rate(
+        config.base_reward_rate,
+        theoretical_rewards,
+        config.theoretical_rewards_ema,
+        config.rate_ema_window,
+        target_rewards,
+        config.bus_rewards,
+        epochs_elapsed,
+    );
+
+    Ok(())
+}
+
+/// Mints new tokens to the given token account, signed by the treasury as mint authority.
+fn mint_rewards<'info>(
+    mint_info: &AccountInfo<'info>,
+    destination_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
     solana_program::program::invoke_signed(
         &spl_token::instruction::mint_to(
             &spl_token::id(),
             mint_info.key,
-            treasury_tokens_info.key,
+            destination_info.key,
             treasury_info.key,
             &[treasury_info.key],
             amount,
@@ -132,13 +288,138 @@ pub fn process_reset<'a, 'info>(
         &[
             token_program.clone(),
             mint_info.clone(),
+            destination_info.clone(),
+            treasury_info.clone(),
+        ],
+        &[&[TREASURY, &[TREASURY_BUMP]]],
+    )
+}
+
+/// Transfers tokens held by the treasury to the given token account.
+fn transfer_rewards<'info>(
+    treasury_tokens_info: &AccountInfo<'info>,
+    destination_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    solana_program::program::invoke_signed(
+        &spl_token::instruction::transfer(
+            &spl_token::id(),
+            treasury_tokens_info.key,
+            destination_info.key,
+            treasury_info.key,
+            &[treasury_info.key],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
             treasury_tokens_info.clone(),
+            destination_info.clone(),
             treasury_info.clone(),
         ],
         &[&[TREASURY, &[TREASURY_BUMP]]],
-    )?;
+    )
+}
 
-    Ok(())
+/// This function tapers an epoch reward quantity in proportion to the supply which remains to be
+/// mined. Emissions decay asymptotically as the supply approaches the max supply, so the supply
+/// converges to the cap rather than hitting it abruptly.
+///
+/// tapered_rewards = rewards * (max_supply - supply) / max_supply
+///
+/// As long as the rewards do not exceed the max supply, the tapered rewards never exceed the
+/// remaining supply, so the max supply can never be exceeded.
+pub(crate) fn calculate_tapered_rewards(rewards: u64, supply: u64) -> u64 {
+    (rewards as u128)
+        .saturating_mul(MAX_SUPPLY.saturating_sub(supply) as u128)
+        .saturating_div(MAX_SUPPLY as u128) as u64
+}
+
+/// Returns true once emissions have tapered off completely, that is when the supply is so close to
+/// the max supply that not a single grain would be allocated to a bus. From then on, the program
+/// runs in its terminal mode where mining is funded by the treasury reward pool rather than by
+/// minting, and claim penalties refill the pool instead of being burned.
+///
+/// The threshold is measured against the smallest possible bus allocation, so it depends on the
+/// supply alone and cannot be moved by the admin changing the epoch duration or bus count. Nothing
+/// is burned in the terminal mode, so once converged the supply stays converged.
+pub(crate) fn is_supply_converged(supply: u64) -> bool {
+    calculate_tapered_rewards(
+        bus_epoch_rewards(MIN_EPOCH_DURATION, MAX_BUS_COUNT as u64),
+        supply,
+    )
+    .eq(&0)
+}
+
+/// This function updates an exponential moving average of the theoretical rewards per epoch with the
+/// rewards of the last epoch. An empty average is seeded with the last epoch's rewards.
+///
+/// new_ema = (current_ema * (window - 1) + epoch_rewards) / window
+///
+/// The new average always lies between the current average and the last epoch's rewards.
+pub(crate) fn calculate_rewards_ema(current_ema: u64, epoch_rewards: u64, window: u64) -> u64 {
+    if current_ema.eq(&0) || window.le(&1) {
+        return epoch_rewards;
+    }
+    (current_ema as u128)
+        .saturating_mul(window.saturating_sub(1) as u128)
+        .saturating_add(epoch_rewards as u128)
+        .saturating_div(window as u128) as u64
+}
+
+/// This function calculates how many full epochs have elapsed since the last reset. The first reset
+/// after initialization always counts as a single epoch, since no epoch has been started yet.
+pub(crate) fn calculate_epochs_elapsed(last_reset_at: i64, now: i64, epoch_duration: i64) -> u64 {
+    if last_reset_at.eq(&0) {
+        return 1;
+    }
+    now.saturating_sub(last_reset_at)
+        .max(0)
+        .saturating_div(epoch_duration.max(1)) as u64
+}
+
+/// This function applies the reward rate update once for every epoch elapsed since the last reset, up
+/// to MAX_CATCH_UP_EPOCHS. After each step, the theoretical rewards and their moving average are
+/// expressed at the new rate, so the next step sees the demand the new rate would have produced.
+///
+/// Returns the new reward rate and the new moving average of the theoretical rewards.
+pub(crate) fn calculate_caught_up_reward_rate(
+    current_rate: u64,
+    epoch_rewards: u64,
+    rewards_ema: u64,
+    ema_window: u64,
+    target_rewards: u64,
+    max_rate: u64,
+    epochs_elapsed: u64,
+) -> (u64, u64) {
+    let mut rate = current_rate;
+    let mut epoch_rewards = epoch_rewards;
+    let mut rewards_ema = rewards_ema;
+    for _ in 0..epochs_elapsed.clamp(1, MAX_CATCH_UP_EPOCHS) {
+        // Average theoretical rewards over multiple epochs, if enabled
+        let theoretical_rewards = if ema_window.gt(&0) {
+            rewards_ema = calculate_rewards_ema(rewards_ema, epoch_rewards, ema_window);
+            rewards_ema
+        } else {
+            epoch_rewards
+        };
+
+        // Update the reward rate and express the rewards at the new rate
+        let new_rate =
+            calculate_new_reward_rate(rate, theoretical_rewards, target_rewards, max_rate);
+        epoch_rewards = rescale_rewards(epoch_rewards, new_rate, rate);
+        rewards_ema = rescale_rewards(rewards_ema, new_rate, rate);
+        rate = new_rate;
+    }
+    (rate, rewards_ema)
+}
+
+/// Scales a quantity of rewards observed at one reward rate to another reward rate.
+fn rescale_rewards(rewards: u64, new_rate: u64, current_rate: u64) -> u64 {
+    (rewards as u128)
+        .saturating_mul(new_rate as u128)
+        .saturating_div(current_rate.max(1) as u128) as u64
 }
 
 /// This function calculates what the new reward rate should be based on how many total rewards
@@ -147,10 +428,17 @@ pub fn process_reset<'a, 'info>(
 ///
 /// new_rate = current_rate * (target_rewards / actual_rewards)
 ///
+/// The target rewards are those of the next epoch, which taper alongside the emission schedule.
+///
 /// The new rate is then smoothed by a constant factor to avoid large fluctuations. In Ore's case,
-/// the epochs are short (60 seconds) so a smoothing factor of 2 has been chosen. That is, the reward rate
+/// the epochs are short (1 to 5 minutes) so a smoothing factor of 2 has been chosen. That is, the reward rate
 /// can at most double or halve from one epoch to the next.
-pub(crate) fn calculate_new_reward_rate(current_rate: u64, epoch_rewards: u64) -> u64 {
+pub(crate) fn calculate_new_reward_rate(
+    current_rate: u64,
+    epoch_rewards: u64,
+    target_rewards: u64,
+    max_rate: u64,
+) -> u64 {
     // Avoid division by zero. Leave the reward rate unchanged, if detected.
     if epoch_rewards.eq(&0) {
         return current_rate;
@@ -158,7 +446,7 @@ pub(crate) fn calculate_new_reward_rate(current_rate: u64, epoch_rewards: u64) -
 
     // Calculate new reward rate.
     let new_rate = (current_rate as u128)
-        .saturating_mul(TARGET_EPOCH_REWARDS as u128)
+        .saturating_mul(target_rewards as u128)
         .saturating_div(epoch_rewards as u128) as u64;
 
     // Smooth reward rate so it cannot change by more than a constant factor from one epoch to the next.
@@ -166,8 +454,8 @@ pub(crate) fn calculate_new_reward_rate(current_rate: u64, epoch_rewards: u64) -
     let new_rate_max = current_rate.saturating_mul(SMOOTHING_FACTOR);
     let new_rate_smoothed = new_rate_min.max(new_rate_max.min(new_rate));
 
-    // Prevent reward rate from dropping below 1 or exceeding BUS_EPOCH_REWARDS and return.
-    new_rate_smoothed.max(1).min(BUS_EPOCH_REWARDS)
+    // Prevent reward rate from exceeding the max rate (the bus epoch rewards) or dropping below 1 and return.
+    new_rate_smoothed.min(max_rate).max(1)
 }
 
 #[cfg(test)]
@@ -175,31 +463,95 @@ mod tests {
     use rand::{distributions::Uniform, Rng};
 
     use crate::{
-        calculate_new_reward_rate, BUS_EPOCH_REWARDS, MAX_EPOCH_REWARDS, SMOOTHING_FACTOR,
-        TARGET_EPOCH_REWARDS,
+        bus_epoch_rewards, calculate_caught_up_reward_rate, calculate_epochs_elapsed,
+        calculate_new_reward_rate, calculate_rewards_ema, calculate_tapered_rewards,
+        is_supply_converged, max_epoch_rewards, target_epoch_rewards, INITIAL_BUS_COUNT,
+        MAX_BUS_COUNT, MAX_CATCH_UP_EPOCHS, MAX_EPOCH_DURATION, MAX_RATE_EMA_WINDOW, MAX_SUPPLY,
+        MIN_EPOCH_DURATION, ONE_MINUTE, SMOOTHING_FACTOR,
     };
 
     const FUZZ_SIZE: u64 = 10_000;
 
+    const EPOCH_DURATION: i64 = MIN_EPOCH_DURATION;
+    const TARGET_EPOCH_REWARDS: u64 = target_epoch_rewards(EPOCH_DURATION);
+    const MAX_EPOCH_REWARDS: u64 = max_epoch_rewards(EPOCH_DURATION);
+    const BUS_COUNT: u64 = INITIAL_BUS_COUNT as u64;
+    const BUS_EPOCH_REWARDS: u64 = bus_epoch_rewards(EPOCH_DURATION, BUS_COUNT);
+
+    #[test]
+    fn test_calculate_epochs_elapsed_genesis() {
+        let epochs = calculate_epochs_elapsed(0, 1_700_000_000, EPOCH_DURATION);
+        assert!(epochs.eq(&1));
+    }
+
+    #[test]
+    fn test_calculate_epochs_elapsed_early() {
+        let last_reset_at = 1_700_000_000;
+        let epochs = calculate_epochs_elapsed(
+            last_reset_at,
+            last_reset_at + EPOCH_DURATION - 1,
+            EPOCH_DURATION,
+        );
+        assert!(epochs.eq(&0));
+    }
+
+    #[test]
+    fn test_calculate_epochs_elapsed_clock_drift() {
+        let last_reset_at = 1_700_000_000;
+        let epochs = calculate_epochs_elapsed(
+            last_reset_at,
+            last_reset_at - EPOCH_DURATION,
+            EPOCH_DURATION,
+        );
+        assert!(epochs.eq(&0));
+    }
+
+    #[test]
+    fn test_calculate_epochs_elapsed_skipped() {
+        let last_reset_at = 1_700_000_000;
+        let epochs = calculate_epochs_elapsed(
+            last_reset_at,
+            last_reset_at + EPOCH_DURATION,
+            EPOCH_DURATION,
+        );
+        assert!(epochs.eq(&1));
+        let epochs = calculate_epochs_elapsed(
+            last_reset_at,
+            last_reset_at + 7 * EPOCH_DURATION + 1,
+            EPOCH_DURATION,
+        );
+        assert!(epochs.eq(&7));
+    }
+
     #[test]
     fn test_calculate_new_reward_rate_target() {
         let current_rate = 1000;
-        let new_rate = calculate_new_reward_rate(current_rate, TARGET_EPOCH_REWARDS);
+        let new_rate = calculate_new_reward_rate(
+            current_rate,
+            TARGET_EPOCH_REWARDS,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+        );
         assert!(new_rate.eq(&current_rate));
     }
 
     #[test]
     fn test_calculate_new_reward_rate_div_by_zero() {
         let current_rate = 1000;
-        let new_rate = calculate_new_reward_rate(current_rate, 0);
+        let new_rate =
+            calculate_new_reward_rate(current_rate, 0, TARGET_EPOCH_REWARDS, BUS_EPOCH_REWARDS);
         assert!(new_rate.eq(&current_rate));
     }
 
     #[test]
     fn test_calculate_new_reward_rate_lower() {
         let current_rate = 1000;
-        let new_rate =
-            calculate_new_reward_rate(current_rate, TARGET_EPOCH_REWARDS.saturating_add(1_000_000));
+        let new_rate = calculate_new_reward_rate(
+            current_rate,
+            TARGET_EPOCH_REWARDS.saturating_add(1_000_000),
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+        );
         assert!(new_rate.lt(&current_rate));
     }
 
@@ -210,7 +562,12 @@ mod tests {
             let current_rate: u64 = rng.sample(Uniform::new(1, BUS_EPOCH_REWARDS));
             let actual_rewards: u64 =
                 rng.sample(Uniform::new(TARGET_EPOCH_REWARDS, MAX_EPOCH_REWARDS));
-            let new_rate = calculate_new_reward_rate(current_rate, actual_rewards);
+            let new_rate = calculate_new_reward_rate(
+                current_rate,
+                actual_rewards,
+                TARGET_EPOCH_REWARDS,
+                BUS_EPOCH_REWARDS,
+            );
             assert!(new_rate.lt(&current_rate));
         }
     }
@@ -218,8 +575,12 @@ mod tests {
     #[test]
     fn test_calculate_new_reward_rate_higher() {
         let current_rate = 1000;
-        let new_rate =
-            calculate_new_reward_rate(current_rate, TARGET_EPOCH_REWARDS.saturating_sub(1_000_000));
+        let new_rate = calculate_new_reward_rate(
+            current_rate,
+            TARGET_EPOCH_REWARDS.saturating_sub(1_000_000),
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+        );
         println!("{:?} {:?}", new_rate, current_rate);
         assert!(new_rate.gt(&current_rate));
     }
@@ -230,7 +591,12 @@ mod tests {
         for _ in 0..FUZZ_SIZE {
             let current_rate: u64 = rng.sample(Uniform::new(1, BUS_EPOCH_REWARDS));
             let actual_rewards: u64 = rng.sample(Uniform::new(1, TARGET_EPOCH_REWARDS));
-            let new_rate = calculate_new_reward_rate(current_rate, actual_rewards);
+            let new_rate = calculate_new_reward_rate(
+                current_rate,
+                actual_rewards,
+                TARGET_EPOCH_REWARDS,
+                BUS_EPOCH_REWARDS,
+            );
             assert!(new_rate.gt(&current_rate));
         }
     }
@@ -238,26 +604,346 @@ mod tests {
     #[test]
     fn test_calculate_new_reward_rate_max_smooth() {
         let current_rate = 1000;
-        let new_rate = calculate_new_reward_rate(current_rate, 1);
+        let new_rate =
+            calculate_new_reward_rate(current_rate, 1, TARGET_EPOCH_REWARDS, BUS_EPOCH_REWARDS);
         assert!(new_rate.eq(&current_rate.saturating_mul(SMOOTHING_FACTOR)));
     }
 
     #[test]
     fn test_calculate_new_reward_rate_min_smooth() {
         let current_rate = 1000;
-        let new_rate = calculate_new_reward_rate(current_rate, u64::MAX);
+        let new_rate = calculate_new_reward_rate(
+            current_rate,
+            u64::MAX,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+        );
         assert!(new_rate.eq(&current_rate.saturating_div(SMOOTHING_FACTOR)));
     }
 
     #[test]
     fn test_calculate_new_reward_rate_max_inputs() {
-        let new_rate = calculate_new_reward_rate(BUS_EPOCH_REWARDS, MAX_EPOCH_REWARDS);
+        let new_rate = calculate_new_reward_rate(
+            BUS_EPOCH_REWARDS,
+            MAX_EPOCH_REWARDS,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+        );
         assert!(new_rate.eq(&BUS_EPOCH_REWARDS.saturating_div(SMOOTHING_FACTOR)));
     }
 
     #[test]
     fn test_calculate_new_reward_rate_min_inputs() {
-        let new_rate = calculate_new_reward_rate(1, 1);
+        let new_rate = calculate_new_reward_rate(1, 1, TARGET_EPOCH_REWARDS, BUS_EPOCH_REWARDS);
         assert!(new_rate.eq(&1u64.saturating_mul(SMOOTHING_FACTOR)));
     }
+
+    #[test]
+    fn test_calculate_new_reward_rate_target_all_durations() {
+        let current_rate = 1000;
+        let mut epoch_duration = MIN_EPOCH_DURATION;
+        while epoch_duration.le(&MAX_EPOCH_DURATION) {
+            let target_rewards = target_epoch_rewards(epoch_duration);
+            let new_rate = calculate_new_reward_rate(
+                current_rate,
+                target_rewards,
+                target_rewards,
+                bus_epoch_rewards(epoch_duration, BUS_COUNT),
+            );
+            assert!(new_rate.eq(&current_rate));
+            epoch_duration += ONE_MINUTE;
+        }
+    }
+
+    #[test]
+    fn test_epoch_rewards_scale_with_duration() {
+        let minutes = (MAX_EPOCH_DURATION / ONE_MINUTE) as u64;
+        assert!(target_epoch_rewards(MAX_EPOCH_DURATION).eq(&(TARGET_EPOCH_REWARDS * minutes)));
+        assert!(max_epoch_rewards(MAX_EPOCH_DURATION).eq(&(MAX_EPOCH_REWARDS * minutes)));
+        assert!(bus_epoch_rewards(MAX_EPOCH_DURATION, BUS_COUNT).eq(&(BUS_EPOCH_REWARDS * minutes)));
+    }
+
+    #[test]
+    fn test_calculate_epochs_elapsed_long_epoch() {
+        let last_reset_at = 1_700_000_000;
+        let epochs = calculate_epochs_elapsed(
+            last_reset_at,
+            last_reset_at + ONE_MINUTE,
+            MAX_EPOCH_DURATION,
+        );
+        assert!(epochs.eq(&0));
+        let epochs = calculate_epochs_elapsed(
+            last_reset_at,
+            last_reset_at + 2 * MAX_EPOCH_DURATION,
+            MAX_EPOCH_DURATION,
+        );
+        assert!(epochs.eq(&2));
+    }
+
+    #[test]
+    fn test_calculate_new_reward_rate_max_bus_count() {
+        let max_rate = bus_epoch_rewards(EPOCH_DURATION, MAX_BUS_COUNT as u64);
+        let new_rate = calculate_new_reward_rate(max_rate, 1, TARGET_EPOCH_REWARDS, max_rate);
+        assert!(new_rate.eq(&max_rate));
+        assert!(max_rate.lt(&BUS_EPOCH_REWARDS));
+    }
+
+    #[test]
+    fn test_calculate_tapered_rewards_genesis() {
+        let rewards = calculate_tapered_rewards(MAX_EPOCH_REWARDS, 0);
+        assert!(rewards.eq(&MAX_EPOCH_REWARDS));
+    }
+
+    #[test]
+    fn test_calculate_tapered_rewards_half() {
+        let rewards = calculate_tapered_rewards(MAX_EPOCH_REWARDS, MAX_SUPPLY / 2);
+        assert!(rewards.eq(&(MAX_EPOCH_REWARDS / 2)));
+    }
+
+    #[test]
+    fn test_calculate_tapered_rewards_cap() {
+        assert!(calculate_tapered_rewards(MAX_EPOCH_REWARDS, MAX_SUPPLY).eq(&0));
+        assert!(calculate_tapered_rewards(MAX_EPOCH_REWARDS, u64::MAX).eq(&0));
+    }
+
+    #[test]
+    fn test_calculate_tapered_rewards_fuzz() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..FUZZ_SIZE {
+            let rewards: u64 = rng.sample(Uniform::new(0, MAX_SUPPLY));
+            let supply: u64 = rng.sample(Uniform::new(0, MAX_SUPPLY));
+            let tapered = calculate_tapered_rewards(rewards, supply);
+            assert!(tapered.le(&rewards));
+            assert!(tapered.le(&(MAX_SUPPLY - supply)));
+            assert!(calculate_tapered_rewards(rewards, supply + 1).le(&tapered));
+        }
+    }
+
+    #[test]
+    fn test_calculate_tapered_rewards_converges() {
+        // Mine the full allocation every epoch. A large allocation keeps the simulation short.
+        let rewards = MAX_SUPPLY / 1_000;
+        let mut supply = 0u64;
+        loop {
+            let tapered = calculate_tapered_rewards(rewards, supply);
+            if tapered.eq(&0) {
+                break;
+            }
+            supply += tapered;
+            assert!(supply.le(&MAX_SUPPLY));
+        }
+
+        // Emissions only stop once the remaining supply is too small to be tapered into a single grain
+        let remaining = MAX_SUPPLY - supply;
+        assert!(remaining.le(&(MAX_SUPPLY / rewards)));
+    }
+
+    #[test]
+    fn test_calculate_new_reward_rate_tapered() {
+        let current_rate = 1000;
+        let supply = MAX_SUPPLY / 2;
+        let new_rate = calculate_new_reward_rate(
+            current_rate,
+            TARGET_EPOCH_REWARDS / 2,
+            calculate_tapered_rewards(TARGET_EPOCH_REWARDS, supply),
+            calculate_tapered_rewards(BUS_EPOCH_REWARDS, supply),
+        );
+        assert!(new_rate.eq(&current_rate));
+    }
+
+    #[test]
+    fn test_is_supply_converged() {
+        assert!(!is_supply_converged(0));
+        assert!(!is_supply_converged(MAX_SUPPLY / 2));
+        assert!(is_supply_converged(MAX_SUPPLY));
+    }
+
+    #[test]
+    fn test_is_supply_converged_config_independent() {
+        // Find the supply at which emissions taper off for the smallest bus allocation
+        let min_bus_rewards = bus_epoch_rewards(MIN_EPOCH_DURATION, MAX_BUS_COUNT as u64);
+        let threshold = MAX_SUPPLY - MAX_SUPPLY.div_ceil(min_bus_rewards) + 1;
+        assert!(!is_supply_converged(threshold - 1));
+        assert!(is_supply_converged(threshold));
+
+        // Every configuration still funds its busses until then
+        for epoch_duration in [MIN_EPOCH_DURATION, MAX_EPOCH_DURATION] {
+            for bus_count in 1..=MAX_BUS_COUNT as u64 {
+                let bus_rewards = bus_epoch_rewards(epoch_duration, bus_count);
+                assert!(calculate_tapered_rewards(bus_rewards, threshold - 1).gt(&0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_rewards_ema_seed() {
+        let ema = calculate_rewards_ema(0, TARGET_EPOCH_REWARDS, MAX_RATE_EMA_WINDOW);
+        assert!(ema.eq(&TARGET_EPOCH_REWARDS));
+    }
+
+    #[test]
+    fn test_calculate_rewards_ema_window_one() {
+        let ema = calculate_rewards_ema(MAX_EPOCH_REWARDS, TARGET_EPOCH_REWARDS, 1);
+        assert!(ema.eq(&TARGET_EPOCH_REWARDS));
+    }
+
+    #[test]
+    fn test_calculate_rewards_ema_dampens() {
+        // A single epoch with no miners only moves the average by a fraction of the window
+        let ema = calculate_rewards_ema(TARGET_EPOCH_REWARDS, 0, 10);
+        assert!(ema.eq(&(TARGET_EPOCH_REWARDS / 10 * 9)));
+        let new_rate =
+            calculate_new_reward_rate(1000, ema, TARGET_EPOCH_REWARDS, BUS_EPOCH_REWARDS);
+        assert!(new_rate.eq(&1111));
+    }
+
+    #[test]
+    fn test_calculate_rewards_ema_fuzz() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..FUZZ_SIZE {
+            let current_ema: u64 = rng.sample(Uniform::new(1, u64::MAX));
+            let epoch_rewards: u64 = rng.sample(Uniform::new(0, u64::MAX));
+            let window: u64 = rng.sample(Uniform::new_inclusive(1, MAX_RATE_EMA_WINDOW));
+            let ema = calculate_rewards_ema(current_ema, epoch_rewards, window);
+            assert!(ema.ge(&current_ema.min(epoch_rewards)));
+            assert!(ema.le(&current_ema.max(epoch_rewards)));
+        }
+    }
+
+    #[test]
+    fn test_calculate_new_reward_rate_ema_lower_fuzz() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..FUZZ_SIZE {
+            let current_rate: u64 = rng.sample(Uniform::new(1, BUS_EPOCH_REWARDS));
+            let current_ema: u64 =
+                rng.sample(Uniform::new(TARGET_EPOCH_REWARDS, MAX_EPOCH_REWARDS));
+            let actual_rewards: u64 =
+                rng.sample(Uniform::new(TARGET_EPOCH_REWARDS, MAX_EPOCH_REWARDS));
+            let window: u64 = rng.sample(Uniform::new_inclusive(1, MAX_RATE_EMA_WINDOW));
+            let ema = calculate_rewards_ema(current_ema, actual_rewards, window);
+            let new_rate = calculate_new_reward_rate(
+                current_rate,
+                ema,
+                TARGET_EPOCH_REWARDS,
+                BUS_EPOCH_REWARDS,
+            );
+            assert!(new_rate.lt(&current_rate));
+            assert!(new_rate.ge(&current_rate.saturating_div(SMOOTHING_FACTOR).max(1)));
+        }
+    }
+
+    #[test]
+    fn test_calculate_new_reward_rate_ema_higher_fuzz() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..FUZZ_SIZE {
+            let current_rate: u64 = rng.sample(Uniform::new(1, BUS_EPOCH_REWARDS));
+            let current_ema: u64 = rng.sample(Uniform::new(1, TARGET_EPOCH_REWARDS));
+            let actual_rewards: u64 = rng.sample(Uniform::new(1, TARGET_EPOCH_REWARDS));
+            let window: u64 = rng.sample(Uniform::new_inclusive(1, MAX_RATE_EMA_WINDOW));
+            let ema = calculate_rewards_ema(current_ema, actual_rewards, window);
+            let new_rate = calculate_new_reward_rate(
+                current_rate,
+                ema,
+                TARGET_EPOCH_REWARDS,
+                BUS_EPOCH_REWARDS,
+            );
+            assert!(new_rate.gt(&current_rate));
+            assert!(new_rate.le(&current_rate.saturating_mul(SMOOTHING_FACTOR)));
+        }
+    }
+
+    #[test]
+    fn test_calculate_caught_up_reward_rate_single_epoch() {
+        let current_rate = 1000;
+        let epoch_rewards = TARGET_EPOCH_REWARDS * 3;
+        let (new_rate, ema) = calculate_caught_up_reward_rate(
+            current_rate,
+            epoch_rewards,
+            0,
+            0,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+            1,
+        );
+        let expected_rate = calculate_new_reward_rate(
+            current_rate,
+            epoch_rewards,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+        );
+        assert!(new_rate.eq(&expected_rate));
+        assert!(ema.eq(&0));
+    }
+
+    #[test]
+    fn test_calculate_caught_up_reward_rate_idle_epochs() {
+        let current_rate = 1024;
+        let epoch_rewards = TARGET_EPOCH_REWARDS * 16;
+        for (epochs_elapsed, expected_rate) in [(1, 512), (2, 256), (4, 64), (7, 64)] {
+            let (new_rate, _) = calculate_caught_up_reward_rate(
+                current_rate,
+                epoch_rewards,
+                0,
+                0,
+                TARGET_EPOCH_REWARDS,
+                BUS_EPOCH_REWARDS,
+                epochs_elapsed,
+            );
+            assert!(new_rate.eq(&expected_rate));
+        }
+    }
+
+    #[test]
+    fn test_calculate_caught_up_reward_rate_bounded() {
+        let current_rate = BUS_EPOCH_REWARDS;
+        let (capped_rate, _) = calculate_caught_up_reward_rate(
+            current_rate,
+            u64::MAX,
+            0,
+            0,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+            MAX_CATCH_UP_EPOCHS,
+        );
+        let (new_rate, _) = calculate_caught_up_reward_rate(
+            current_rate,
+            u64::MAX,
+            0,
+            0,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+            u64::MAX,
+        );
+        assert!(new_rate.eq(&capped_rate));
+        assert!(new_rate.ge(&1));
+    }
+
+    #[test]
+    fn test_calculate_caught_up_reward_rate_ema() {
+        let current_rate = 1024;
+        let epoch_rewards = TARGET_EPOCH_REWARDS * 16;
+        let window = 4;
+        let (new_rate, ema) = calculate_caught_up_reward_rate(
+            current_rate,
+            epoch_rewards,
+            TARGET_EPOCH_REWARDS,
+            window,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+            1,
+        );
+        let expected_ema = calculate_rewards_ema(TARGET_EPOCH_REWARDS, epoch_rewards, window);
+        assert!(new_rate.eq(&512));
+        assert!(ema.eq(&(expected_ema / 2)));
+        let (new_rate, _) = calculate_caught_up_reward_rate(
+            current_rate,
+            epoch_rewards,
+            TARGET_EPOCH_REWARDS,
+            window,
+            TARGET_EPOCH_REWARDS,
+            BUS_EPOCH_REWARDS,
+            MAX_CATCH_UP_EPOCHS,
+        );
+        assert!(new_rate.abs_diff(64).le(&1));
+    }
 }
@@ -4,18 +4,44 @@ use solana_program::{
 };
 
 use crate::{
-    instruction::StakeArgs, loaders::*, state::Proof, utils::AccountDeserialize, MINT_ADDRESS,
-    TREASURY_ADDRESS,
+    error::OreError,
+    instruction::StakeArgs,
+    loaders::*,
+    state::{Config, Proof, Treasury},
+    utils::{calculate_stake_weighted_at, lock_stake, AccountDeserialize},
+    MINT_ADDRESS, PAUSE_STAKE, STAKE_LOCK_DURATIONS,
 };
 
 /// Stake deposits Ore into a miner's proof account to earn multiplier. Its responsibilies include:
-/// 1. Transfer tokens from the miner to the treasury account.
-/// 2. Increment the miner's claimable balance.
+/// 1. Transfer tokens from the signer to the treasury account.
+/// 2. Increment the miner's staked balance.
+/// 3. Blend the deposit into the age of the miner's stake.
+/// 4. Lock the deposit for the chosen lock duration, if any.
 ///
 /// Safety requirements:
 /// - Stake is a permissionless instruction and can be called by any user.
-/// - Can only succeed if the amount is less than or equal to the miner's transferable tokens.
-/// - The provided beneficiary, proof, sender, treasury token account, and token program must be valid.
+/// - Can only succeed if staking is not paused.
+/// - Can only succeed if the amount is greater than zero.
+/// - Can only succeed if the amount is less than or equal to the signer's transferable tokens.
+/// - Can only succeed if the lock tier is valid, and not lower than the tier of an active lock.
+/// - Can only succeed if the lock tier is 0 when depositing into another miner's proof.
+/// - The provided config, proof, sender, treasury, treasury token account, and token program must be valid.
+///
+/// Discussion:
+/// - Staked tokens are tracked separately from mined rewards. They can only be withdrawn with the
+///   unstake instruction and are never subject to the early claim burn.
+/// - The staking multiplier ramps up linearly with the age of the stake. A deposit does not reset the
+///   age of the existing stake. Instead, the stake age becomes the balance-weighted average of the age
+///   of the existing stake and the new deposit, which starts at zero.
+/// - Locking stake raises the ceiling of the staking multiplier for the locked amount, and prevents it from
+///   being unstaked until the lock expires. A proof holds a single lock. Locking more stake while a lock is
+///   active adds to it, at the higher of the two tiers and the later of the two expiry times, so a lock can
+///   only ever be extended. A deposit at a lower tier than an active lock is rejected, since it would
+///   otherwise lower the tier of the whole lock. Depositing with tier 0 does not affect an active lock.
+/// - Any signer may deposit into any proof, for example to sponsor another miner. Sponsored stake belongs
+///   to the proof and can only be withdrawn by the proof authority. Sponsors cannot lock stake, and their
+///   deposits do not restart the unstake cooldown, so a third party can never prevent a miner from
+///   withdrawing their own stake.
 pub fn process_stake<'a, 'info>(
     _program_id: &Pubkey,
     accounts: &'a [AccountInfo<'info>],
@@ -24,30 +50,71 @@ pub fn process_stake<'a, 'info>(
     // Parse args
     let args = StakeArgs::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
+    if amount.eq(&0) {
+        return Err(OreError::StakeTooSmall.into());
+    }
+    let lock_duration = *STAKE_LOCK_DURATIONS
+        .get(args.lock_tier as usize)
+        .ok_or(OreError::StakeLockInvalid)?;
 
     // Load accounts
-    let [signer, proof_info, sender_info, treasury_tokens_info, token_program] = accounts else {
+    let [signer, config_info, proof_info, sender_info, treasury_info, treasury_tokens_info, token_program] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     load_signer(signer)?;
-    load_proof(proof_info, signer.key, true)?;
+    load_config(config_info, false)?;
+    load_any_proof(proof_info, true)?;
     load_token_account(sender_info, Some(signer.key), &MINT_ADDRESS, true)?;
+    load_treasury(treasury_info, true)?;
     load_token_account(
         treasury_tokens_info,
-        Some(&TREASURY_ADDRESS),
+        Some(treasury_info.key),
         &MINT_ADDRESS,
         true,
     )?;
     load_program(token_program, spl_token::id())?;
 
-    // Update proof balance
+    // Validate staking is not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & PAUSE_STAKE).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
+    // Update stake age
     let mut proof_data = proof_info.data.borrow_mut();
     let proof = Proof::try_from_bytes_mut(&mut proof_data)?;
-    proof.balance = proof.balance.saturating_add(amount);
+    let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
+    let is_sponsored = proof.authority.ne(signer.key);
+    if is_sponsored && lock_duration.gt(&0) {
+        return Err(OreError::StakeLockInvalid.into());
+    }
+    proof.stake_weighted_at = calculate_stake_weighted_at(
+        proof.stake,
+        proof.stake_weighted_at,
+        amount,
+        clock.unix_timestamp,
+    );
+
+    // Update staked balance
+    proof.stake = proof.stake.saturating_add(amount);
 
     // Update deposit timestamp
-    let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
-    proof.last_stake_at = clock.unix_timestamp;
+    if !is_sponsored {
+        proof.last_stake_at = clock.unix_timestamp;
+    }
+
+    // Update stake lock
+    if lock_duration.gt(&0) {
+        lock_stake(proof, amount, args.lock_tier as u64, clock.unix_timestamp)?;
+    }
+
+    // Update lifetime deposits
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.total_staked = treasury.total_staked.saturating_add(amount);
 
     // Distribute tokens from signer to treasury
     solana_program::program::invoke(
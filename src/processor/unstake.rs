@@ -0,0 +1,117 @@
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    error::OreError,
+    instruction::UnstakeArgs,
+    loaders::*,
+    state::{Config, Proof, Treasury},
+    utils::{calculate_locked_stake, AccountDeserialize},
+    MINT_ADDRESS, PAUSE_STAKE, TREASURY, TREASURY_BUMP, UNSTAKE_COOLDOWN,
+};
+
+/// Unstake withdraws staked Ore from a miner's proof account. Its responsibilities include:
+/// 1. Decrement the miner's staked balance.
+/// 2. Transfer tokens from the treasury to the beneficiary.
+///
+/// Safety requirements:
+/// - Can only succeed if staking is not paused.
+/// - Can only succeed if the signer is the proof authority.
+/// - Can only succeed if the amount is less than or equal to the miner's staked balance.
+/// - Can only succeed if the cooldown has elapsed since the last stake deposit.
+/// - Can only succeed if the amount does not exceed the miner's unlocked stake.
+/// - The provided beneficiary, config, proof, treasury, treasury token account, and token program must be valid.
+///
+/// Discussion:
+/// - Unlike claim, unstake never burns any of the withdrawn amount. The cooldown prevents stake
+///   from being deposited just before mining and withdrawn immediately after.
+/// - Unstake does not change the stake-weighted deposit time. A withdrawal is taken evenly from every
+///   deposit, so the remaining stake keeps its age.
+pub fn process_unstake<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UnstakeArgs::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts
+    let [signer, beneficiary_info, config_info, proof_info, treasury_info, treasury_tokens_info, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_token_account(beneficiary_info, None, &MINT_ADDRESS, true)?;
+    load_config(config_info, false)?;
+    load_proof(proof_info, signer.key, true)?;
+    load_treasury(treasury_info, true)?;
+    load_token_account(
+        treasury_tokens_info,
+        Some(treasury_info.key),
+        &MINT_ADDRESS,
+        true,
+    )?;
+    load_program(token_program, spl_token::id())?;
+
+    // Validate staking is not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & PAUSE_STAKE).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
+    // Validate cooldown has elapsed
+    let mut proof_data = proof_info.data.borrow_mut();
+    let proof = Proof::try_from_bytes_mut(&mut proof_data)?;
+    let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
+    if proof
+        .last_stake_at
+        .saturating_add(UNSTAKE_COOLDOWN)
+        .gt(&clock.unix_timestamp)
+    {
+        return Err(OreError::UnstakeCooldown.into());
+    }
+
+    // Validate amount does not exceed the unlocked stake
+    let stake = proof
+        .stake
+        .checked_sub(amount)
+        .ok_or(OreError::UnstakeTooLarge)?;
+    if stake.lt(&calculate_locked_stake(proof, clock.unix_timestamp)) {
+        return Err(OreError::StakeLocked.into());
+    }
+
+    // Update staked balance
+    proof.stake = stake;
+
+    // Update lifetime withdrawals
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.total_unstaked = treasury.total_unstaked.saturating_add(amount);
+    drop(treasury_data);
+
+    // Distribute tokens from treasury to beneficiary
+    solana_program::program::invoke_signed(
+        &spl_token::instruction::transfer(
+            &spl_token::id(),
+            treasury_tokens_info.key,
+            beneficiary_info.key,
+            treasury_info.key,
+            &[treasury_info.key],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
+            treasury_tokens_info.clone(),
+            beneficiary_info.clone(),
+            treasury_info.clone(),
+        ],
+        &[&[TREASURY, &[TREASURY_BUMP]]],
+    )?;
+
+    Ok(())
+}
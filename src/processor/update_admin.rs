@@ -5,14 +5,17 @@ use solana_program::{
 
 use crate::{instruction::UpdateAdminArgs, loaders::*, state::Config, utils::AccountDeserialize};
 
-/// UpdateAdmin updates the program's admin account. Its responsibilities include:
-/// 1. Update the treasury admin address.
+/// UpdateAdmin proposes a new admin account for the program. Its responsibilities include:
+/// 1. Update the pending admin address.
 ///
 /// Safety requirements:
 /// - Can only succeed if the signer is the program admin.
-/// - Can only succeed if the provided treasury is valid.
+/// - Can only succeed if the provided config is valid.
 ///
 /// Discussion:
+/// - The admin is not rotated until the proposed key signs an accept admin instruction. This
+///   prevents a mistyped address from permanently locking out every admin instruction.
+/// - Proposing the default pubkey cancels any pending proposal.
 /// - The admin authority has one lever of power: the ability to adjust the global
 ///   mining difficulty. If the difficulty is too easy, miners will find hashes very quickly
 ///   and the bottleneck for mining will shift from local compute to Solana bandwidth. In essence,
@@ -47,8 +50,8 @@ pub fn process_update_admin<'a, 'info>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // Update admin
-    config.admin = args.new_admin;
+    // Update pending admin
+    config.pending_admin = args.new_admin;
 
     Ok(())
 }
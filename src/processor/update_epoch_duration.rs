@@ -0,0 +1,61 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::OreError, instruction::UpdateEpochDurationArgs, loaders::*, state::Config,
+    utils::AccountDeserialize, MAX_EPOCH_DURATION, MIN_EPOCH_DURATION, ONE_MINUTE,
+};
+
+/// UpdateEpochDuration updates the length of each epoch. Its responsibilities include:
+/// 1. Schedule the new epoch duration to take effect at the next reset.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the program admin.
+/// - Can only succeed if the provided config is valid.
+/// - Can only succeed if the new epoch duration is a whole number of minutes within the allowed bounds.
+///
+/// Discussion:
+/// - The target and maximum rewards per epoch scale linearly with the epoch duration, so the
+///   supply growth rate per minute is unaffected.
+/// - The new duration does not take effect until the next reset, which sizes the next allocation for it.
+///   Changing the duration mid-epoch would leave the busses with an allocation sized for the old duration,
+///   which could be mined faster than the maximum supply growth rate if the epoch were shortened.
+/// - The moving average of theoretical rewards is denominated per epoch, so it is discarded and
+///   reseeded by the reset which applies the new duration.
+pub fn process_update_epoch_duration<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UpdateEpochDurationArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+
+    // Validate signer is admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Sanity checks
+    if args.epoch_duration.lt(&(MIN_EPOCH_DURATION as u64))
+        || args.epoch_duration.gt(&(MAX_EPOCH_DURATION as u64))
+        || (args.epoch_duration % ONE_MINUTE as u64).ne(&0)
+    {
+        return Err(OreError::EpochDurationOutOfBounds.into());
+    }
+
+    // Update pending epoch duration
+    config.pending_epoch_duration = args.epoch_duration as i64;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::OreError, instruction::UpdateMinDifficultyArgs, loaders::*, state::Config,
+    utils::AccountDeserialize, MIN_DIFFICULTY_LOWER_BOUND, MIN_DIFFICULTY_UPPER_BOUND,
+};
+
+/// UpdateMinDifficulty updates the minimum difficulty required of submitted hashes. Its responsibilities include:
+/// 1. Update the minimum difficulty.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the program admin.
+/// - Can only succeed if the provided config is valid.
+/// - Can only succeed if the new minimum difficulty is within the allowed bounds.
+///
+/// Discussion:
+/// - The base reward rate is paid out for a hash of exactly the minimum difficulty. Raising the
+///   minimum difficulty by one doubles the work required to earn the base rate, so the reward
+///   rate will adjust upwards over the following epochs to maintain the target supply growth.
+pub fn process_update_min_difficulty<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UpdateMinDifficultyArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+
+    // Validate signer is admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Sanity checks
+    if args.min_difficulty.lt(&(MIN_DIFFICULTY_LOWER_BOUND as u64))
+        || args.min_difficulty.gt(&(MIN_DIFFICULTY_UPPER_BOUND as u64))
+    {
+        return Err(OreError::DifficultyOutOfBounds.into());
+    }
+
+    // Update min difficulty
+    config.min_difficulty = args.min_difficulty;
+
+    Ok(())
+}
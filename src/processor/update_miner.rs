@@ -0,0 +1,39 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{instruction::UpdateMinerArgs, loaders::*, state::Proof, utils::AccountDeserialize};
+
+/// UpdateMiner delegates mining rights of a proof account to a separate key. Its responsibilities include:
+/// 1. Update the proof's miner address.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the proof authority.
+/// - Can only succeed if the provided proof account is valid.
+///
+/// Discussion:
+/// - The miner key may only submit hashes. Claiming, staking, and deregistering still require the
+///   proof authority, so a compromised miner key cannot move the proof's balance.
+pub fn process_update_miner<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UpdateMinerArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, proof_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_proof(proof_info, signer.key, true)?;
+
+    // Update miner
+    let mut proof_data = proof_info.data.borrow_mut();
+    let proof = Proof::try_from_bytes_mut(&mut proof_data)?;
+    proof.miner = args.new_miner;
+
+    Ok(())
+}
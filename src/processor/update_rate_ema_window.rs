@@ -0,0 +1,56 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::OreError, instruction::UpdateRateEmaWindowArgs, loaders::*, state::Config,
+    utils::AccountDeserialize, MAX_RATE_EMA_WINDOW,
+};
+
+/// UpdateRateEmaWindow updates the number of epochs averaged by the reward rate adjustment. Its
+/// responsibilities include:
+/// 1. Update the reward rate EMA window.
+/// 2. Reset the moving average of theoretical rewards.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the program admin.
+/// - Can only succeed if the provided config is valid.
+/// - Can only succeed if the new window does not exceed the max window.
+///
+/// Discussion:
+/// - A window of 0 disables the moving average, so reset only considers the last epoch.
+/// - The moving average is reseeded with the theoretical rewards of the next epoch to be reset.
+pub fn process_update_rate_ema_window<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UpdateRateEmaWindowArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+
+    // Validate signer is admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Sanity checks
+    if args.rate_ema_window.gt(&MAX_RATE_EMA_WINDOW) {
+        return Err(OreError::RateEmaWindowOutOfBounds.into());
+    }
+
+    // Update window and reset moving average
+    config.rate_ema_window = args.rate_ema_window;
+    config.theoretical_rewards_ema = 0;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::OreError, instruction::UpdateResetBountyArgs, loaders::*, state::Config,
+    utils::AccountDeserialize, MAX_RESET_BOUNTY,
+};
+
+/// UpdateResetBounty updates the bounty paid to the signer of each reset. Its responsibilities include:
+/// 1. Update the reset bounty.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the program admin.
+/// - Can only succeed if the provided config is valid.
+/// - Can only succeed if the new reset bounty does not exceed the maximum reset bounty.
+///
+/// Discussion:
+/// - The bounty is paid out of the epoch's unmined rewards, so it never increases the maximum
+///   supply growth rate. Setting the bounty to zero disables it.
+pub fn process_update_reset_bounty<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UpdateResetBountyArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+
+    // Validate signer is admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Sanity checks
+    if args.reset_bounty.gt(&MAX_RESET_BOUNTY) {
+        return Err(OreError::ResetBountyTooLarge.into());
+    }
+
+    // Update reset bounty
+    config.reset_bounty = args.reset_bounty;
+
+    Ok(())
+}
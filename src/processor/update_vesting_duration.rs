@@ -0,0 +1,55 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::OreError, instruction::UpdateVestingDurationArgs, loaders::*, state::Config,
+    utils::AccountDeserialize, MAX_VESTING_DURATION, MIN_VESTING_DURATION,
+};
+
+/// UpdateVestingDuration updates the duration over which vested rewards are released. Its responsibilities include:
+/// 1. Update the vesting duration.
+///
+/// Safety requirements:
+/// - Can only succeed if the signer is the program admin.
+/// - Can only succeed if the provided config is valid.
+/// - Can only succeed if the new vesting duration is within the allowed bounds.
+///
+/// Discussion:
+/// - The new duration only applies to rewards vested from now on. Existing streams keep their end time,
+///   until more rewards are blended into them.
+pub fn process_update_vesting_duration<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = UpdateVestingDurationArgs::try_from_bytes(data)?;
+
+    // Load accounts
+    let [signer, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, true)?;
+
+    // Validate signer is admin
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_bytes_mut(&mut config_data)?;
+    if config.admin.ne(&signer.key) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Sanity checks
+    if args.vesting_duration.lt(&(MIN_VESTING_DURATION as u64))
+        || args.vesting_duration.gt(&(MAX_VESTING_DURATION as u64))
+    {
+        return Err(OreError::VestingDurationOutOfBounds.into());
+    }
+
+    // Update vesting duration
+    config.vesting_duration = args.vesting_duration as i64;
+
+    Ok(())
+}
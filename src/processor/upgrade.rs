@@ -4,7 +4,12 @@ use solana_program::{
 };
 
 use crate::{
-    instruction::StakeArgs, loaders::*, MINT_ADDRESS, MINT_V1_ADDRESS, TREASURY, TREASURY_BUMP,
+    error::OreError,
+    instruction::UpgradeArgs,
+    loaders::*,
+    state::{Config, Treasury},
+    utils::AccountDeserialize,
+    MINT_ADDRESS, MINT_V1_ADDRESS, PAUSE_UPGRADE, TREASURY, TREASURY_BUMP,
 };
 
 /// Upgrade allows a user to migrate a v1 token to a v2 token one-for-one. Its responsibilies include:
@@ -13,29 +18,39 @@ use crate::{
 ///
 /// Safety requirements:
 /// - Upgrade is a permissionless instruction and can be called by any user.
-/// - The provided beneficiary, mint, mint v1, sender, and token program must be valid.
+/// - Can only succeed if upgrades are not paused.
+/// - The provided beneficiary, config, mint, mint v1, sender, treasury, and token program must be valid.
 pub fn process_upgrade<'a, 'info>(
     _program_id: &Pubkey,
     accounts: &'a [AccountInfo<'info>],
     data: &[u8],
 ) -> ProgramResult {
     // Parse args
-    let args = StakeArgs::try_from_bytes(data)?;
+    let args = UpgradeArgs::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
 
     // Load accounts
-    let [signer, beneficiary_info, mint_info, mint_v1_info, sender_info, treasury_info, token_program] =
+    let [signer, beneficiary_info, config_info, mint_info, mint_v1_info, sender_info, treasury_info, token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     load_signer(signer)?;
     load_token_account(beneficiary_info, Some(&signer.key), &MINT_ADDRESS, true)?;
+    load_config(config_info, false)?;
     load_mint(mint_info, MINT_ADDRESS, true)?;
     load_mint(mint_v1_info, MINT_V1_ADDRESS, true)?;
     load_token_account(sender_info, Some(signer.key), &MINT_V1_ADDRESS, true)?;
+    load_treasury(treasury_info, true)?;
     load_program(token_program, spl_token::id())?;
 
+    // Validate upgrades are not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & PAUSE_UPGRADE).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
     // Burn v1 tokens
     solana_program::program::invoke(
         &spl_token::instruction::burn(
@@ -58,6 +73,12 @@ pub fn process_upgrade<'a, 'info>(
     // v1 token has 9 decimals. v2 token has 11.
     let amount_to_mint = amount.saturating_mul(100);
 
+    // Update lifetime upgrades
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.total_upgraded = treasury.total_upgraded.saturating_add(amount_to_mint);
+    drop(treasury_data);
+
     // Mint to the beneficiary account
     solana_program::program::invoke_signed(
         &spl_token::instruction::mint_to(
@@ -0,0 +1,112 @@
+use std::mem::size_of;
+
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::OreError,
+    instruction::VestArgs,
+    loaders::*,
+    state::{Config, Proof, Vesting},
+    utils::{create_pda, AccountDeserialize, Discriminator},
+    PAUSE_CLAIM, VESTING,
+};
+
+/// Vest moves a miner's claimable rewards into a vesting stream. Its responsibilities include:
+/// 1. Initialize the proof's vesting account, if it does not exist yet.
+/// 2. Decrement the miner's claimable balance.
+/// 3. Add the amount to the vesting stream.
+///
+/// Safety requirements:
+/// - Can only succeed if claims are not paused.
+/// - Can only succeed if the signer is the proof authority.
+/// - Can only succeed if the amount is less than or equal to the miner's claimable rewards.
+/// - Can only succeed if the provided vesting account PDA is valid (associated with the proof).
+/// - The provided config, proof, and system program must be valid.
+///
+/// Discussion:
+/// - Vesting is an alternative to claiming which never burns any rewards. Instead of paying the early claim
+///   penalty, the miner waits for the rewards to be released linearly over the vesting duration, after
+///   which they can be withdrawn with the withdraw instruction.
+/// - Tokens remain in the treasury until they are withdrawn, so vesting requires no token transfers.
+/// - Vesting more rewards while a stream is active blends them into it. The end of the stream becomes the
+///   balance-weighted average of the remaining duration of the stream and the current vesting duration,
+///   so rewards which have already vested are never locked again.
+pub fn process_vest<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = VestArgs::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts
+    let [signer, config_info, proof_info, vesting_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_config(config_info, false)?;
+    load_proof(proof_info, signer.key, true)?;
+    load_program(system_program, system_program::id())?;
+
+    // Validate claims are not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & PAUSE_CLAIM).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
+    // Initialize vesting account, if needed
+    let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
+    if vesting_info.data_is_empty() {
+        load_uninitialized_pda(
+            vesting_info,
+            &[VESTING, proof_info.key.as_ref()],
+            args.bump,
+            &crate::id(),
+        )?;
+        create_pda(
+            vesting_info,
+            &crate::id(),
+            8 + size_of::<Vesting>(),
+            &[VESTING, proof_info.key.as_ref(), &[args.bump]],
+            system_program,
+            signer,
+        )?;
+        let mut vesting_data = vesting_info.data.borrow_mut();
+        vesting_data[0] = Vesting::discriminator() as u8;
+        let vesting = Vesting::try_from_bytes_mut(&mut vesting_data)?;
+        vesting.authority = *signer.key;
+        vesting.proof = *proof_info.key;
+        vesting.bump = args.bump as u64;
+        vesting.unlocked = 0;
+        vesting.locked = 0;
+        vesting.updated_at = clock.unix_timestamp;
+        vesting.ends_at = clock.unix_timestamp;
+    } else {
+        load_vesting(vesting_info, signer.key, true)?;
+        let vesting_data = vesting_info.data.borrow();
+        let vesting = Vesting::try_from_bytes(&vesting_data)?;
+        if vesting.proof.ne(proof_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Update miner balance
+    let mut proof_data = proof_info.data.borrow_mut();
+    let proof = Proof::try_from_bytes_mut(&mut proof_data)?;
+    proof.balance = proof
+        .balance
+        .checked_sub(amount)
+        .ok_or(OreError::ClaimTooLarge)?;
+
+    // Update vesting stream
+    let mut vesting_data = vesting_info.data.borrow_mut();
+    let vesting = Vesting::try_from_bytes_mut(&mut vesting_data)?;
+    vesting.deposit(amount, config.vesting_duration, clock.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    error::OreError,
+    instruction::WithdrawArgs,
+    loaders::*,
+    state::{Config, Treasury, Vesting},
+    utils::AccountDeserialize,
+    MINT_ADDRESS, PAUSE_CLAIM, TREASURY, TREASURY_BUMP,
+};
+
+/// Withdraw distributes vested Ore from the treasury to a miner. Its responsibilities include:
+/// 1. Unlock the rewards which have vested since the last update.
+/// 2. Decrement the miner's vested balance.
+/// 3. Transfer tokens from the treasury to the beneficiary.
+///
+/// Safety requirements:
+/// - Can only succeed if claims are not paused.
+/// - Can only succeed if the signer is the vesting authority.
+/// - Can only succeed if the amount is less than or equal to the miner's vested balance.
+/// - The provided beneficiary, config, vesting, treasury, treasury token account, and token program must be valid.
+///
+/// Discussion:
+/// - Withdrawals are never subject to the early claim burn, because the rewards have already been held back
+///   for the vesting duration.
+/// - Withdrawals do not require the proof, so vested rewards remain withdrawable after a proof is deregistered.
+pub fn process_withdraw<'a, 'info>(
+    _program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse args
+    let args = WithdrawArgs::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts
+    let [signer, beneficiary_info, config_info, vesting_info, treasury_info, treasury_tokens_info, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_signer(signer)?;
+    load_token_account(beneficiary_info, None, &MINT_ADDRESS, true)?;
+    load_config(config_info, false)?;
+    load_vesting(vesting_info, signer.key, true)?;
+    load_treasury(treasury_info, true)?;
+    load_token_account(
+        treasury_tokens_info,
+        Some(treasury_info.key),
+        &MINT_ADDRESS,
+        true,
+    )?;
+    load_program(token_program, spl_token::id())?;
+
+    // Validate claims are not paused
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_bytes(&config_data)?;
+    if (config.paused & PAUSE_CLAIM).ne(&0) {
+        return Err(OreError::IsPaused.into());
+    }
+
+    // Update vested balance
+    let mut vesting_data = vesting_info.data.borrow_mut();
+    let vesting = Vesting::try_from_bytes_mut(&mut vesting_data)?;
+    let clock = Clock::get().or(Err(ProgramError::InvalidAccountData))?;
+    vesting.update(clock.unix_timestamp);
+    vesting.unlocked = vesting
+        .unlocked
+        .checked_sub(amount)
+        .ok_or(OreError::WithdrawTooLarge)?;
+
+    // Update lifetime claims
+    let mut treasury_data = treasury_info.data.borrow_mut();
+    let treasury = Treasury::try_from_bytes_mut(&mut treasury_data)?;
+    treasury.total_claimed = treasury.total_claimed.saturating_add(amount);
+    drop(treasury_data);
+
+    // Distribute tokens from treasury to beneficiary
+    solana_program::program::invoke_signed(
+        &spl_token::instruction::transfer(
+            &spl_token::id(),
+            treasury_tokens_info.key,
+            beneficiary_info.key,
+            treasury_info.key,
+            &[treasury_info.key],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
+            treasury_tokens_info.clone(),
+            beneficiary_info.clone(),
+            treasury_info.clone(),
+        ],
+        &[&[TREASURY, &[TREASURY_BUMP]]],
+    )?;
+
+    Ok(())
+}
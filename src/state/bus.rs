@@ -7,14 +7,18 @@ use crate::{
 };
 
 /// Bus accounts are responsible for distributing mining rewards.
-/// There are 8 busses total to minimize write-lock contention and allow for parallel mine operations.
-/// Every epoch, the bus account rewards counters are topped up to 0.25 ORE each (2 ORE split amongst 8 busses).
+/// There are multiple busses to minimize write-lock contention and allow for parallel mine operations.
+/// Every epoch, the maximum epoch rewards are split evenly amongst the busses. Each bus is topped up
+/// lazily by the first mine operation to touch it in a new epoch, so reset never needs to write to it.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
 pub struct Bus {
     /// The ID of the bus account.
     pub id: u64,
 
+    /// The epoch in which the bus was last topped up.
+    pub epoch: u64,
+
     /// The remaining rewards this bus has left to payout in the current epoch epoch.
     pub rewards: u64,
 
@@ -11,18 +11,60 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
 pub struct Config {
-    /// The admin authority with permission to update the difficulty.
+    /// The admin authority with permission to update the program configuration.
     pub admin: Pubkey,
 
+    /// The proposed admin authority, which must accept before the handover takes effect.
+    pub pending_admin: Pubkey,
+
     /// The base reward rate paid out for a hash of minimum difficulty.
     pub base_reward_rate: u64,
 
+    /// The number of epochs averaged by the reward rate adjustment, or 0 to only consider the last epoch.
+    pub rate_ema_window: u64,
+
+    /// The moving average of theoretical rewards per epoch, expressed at the current base reward rate.
+    pub theoretical_rewards_ema: u64,
+
+    /// The number of bus accounts which have been created.
+    pub bus_count: u64,
+
+    /// The number of busses funded by the current epoch's allocation, which excludes busses added mid-epoch.
+    pub funded_bus_count: u64,
+
+    /// The number of the current epoch, incremented by every successful reset.
+    pub epoch: u64,
+
+    /// The duration of an epoch, in seconds.
+    pub epoch_duration: i64,
+
+    /// The epoch duration which takes effect at the next reset, or 0 if it is unchanged.
+    pub pending_epoch_duration: i64,
+
+    /// The total rewards allocated to the busses at the start of the current epoch.
+    pub epoch_rewards: u64,
+
+    /// The rewards allocated to each bus in the current epoch.
+    pub bus_rewards: u64,
+
+    /// The portion of the current epoch's allocation reserved from the treasury reward pool rather than minted.
+    pub pooled_rewards: u64,
+
     /// The timestamp of the last reset
     pub last_reset_at: i64,
 
-    /// Is mining paused.
+    /// The minimum difficulty required of all submitted hashes.
+    pub min_difficulty: u64,
+
+    /// The set of paused instructions, as `PAUSE_*` bitflags.
     pub paused: u64,
 
+    /// The bounty paid to the signer of each reset which advances the epoch.
+    pub reset_bounty: u64,
+
+    /// The duration over which vested rewards are released, in seconds.
+    pub vesting_duration: i64,
+
     /// Seconds prior to a miner's target time during which their hashes will not be penalized.
     pub tolerance_spam: i64,
 
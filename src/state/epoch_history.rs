@@ -0,0 +1,112 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+
+use crate::{
+    impl_account_from_bytes, impl_to_bytes,
+    utils::{AccountDiscriminator, Discriminator},
+    EPOCH_HISTORY_SIZE,
+};
+
+/// EpochHistory is a singleton account which records the outcome of recent epochs in a ring buffer.
+/// Every reset which ends an epoch appends a record, overwriting the oldest once the buffer is full.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+pub struct EpochHistory {
+    /// The total number of records ever appended. The next record is written at `head % EPOCH_HISTORY_SIZE`.
+    pub head: u64,
+
+    /// The records of the most recent epochs.
+    pub records: [EpochRecord; EPOCH_HISTORY_SIZE],
+}
+
+/// EpochRecord summarizes the rewards of a single ended epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct EpochRecord {
+    /// The number of the epoch.
+    pub epoch: u64,
+
+    /// The timestamp of the reset which ended the epoch.
+    pub reset_at: i64,
+
+    /// The base reward rate paid out during the epoch.
+    pub base_reward_rate: u64,
+
+    /// The total rewards allocated to the busses for the epoch.
+    pub epoch_rewards: u64,
+
+    /// The rewards actually mined during the epoch.
+    pub rewards: u64,
+
+    /// The rewards which would have been mined during the epoch if there were no bus limits.
+    pub theoretical_rewards: u64,
+}
+
+impl EpochHistory {
+    /// Appends a record, overwriting the oldest record once the buffer is full.
+    pub fn push(&mut self, record: EpochRecord) {
+        let index = self.head % EPOCH_HISTORY_SIZE as u64;
+        self.records[index as usize] = record;
+        self.head = self.head.saturating_add(1);
+    }
+
+    /// Returns the most recently appended record, if any.
+    pub fn latest(&self) -> Option<&EpochRecord> {
+        if self.head.eq(&0) {
+            return None;
+        }
+        let index = (self.head - 1) % EPOCH_HISTORY_SIZE as u64;
+        Some(&self.records[index as usize])
+    }
+}
+
+impl Discriminator for EpochHistory {
+    fn discriminator() -> AccountDiscriminator {
+        AccountDiscriminator::EpochHistory
+    }
+}
+
+impl_to_bytes!(EpochHistory);
+impl_account_from_bytes!(EpochHistory);
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use crate::{
+        state::{EpochHistory, EpochRecord},
+        EPOCH_HISTORY_SIZE,
+    };
+
+    const SIZE: u64 = EPOCH_HISTORY_SIZE as u64;
+
+    #[test]
+    fn test_epoch_history_push() {
+        for (pushed, latest, records) in [
+            // An empty history has no latest record
+            (0, None, vec![]),
+            // Records are appended in order
+            (2, Some(1), vec![(0, 0), (1, 1)]),
+            // Once full, the oldest records are overwritten
+            (SIZE, Some(SIZE - 1), vec![(0, 0), (3, 3)]),
+            (
+                SIZE + 3,
+                Some(SIZE + 2),
+                vec![(0, SIZE), (2, SIZE + 2), (3, 3)],
+            ),
+        ] {
+            let mut history = EpochHistory::zeroed();
+            for epoch in 0..pushed {
+                history.push(EpochRecord {
+                    epoch,
+                    ..EpochRecord::zeroed()
+                });
+            }
+            assert_eq!(history.head, pushed);
+            assert_eq!(history.latest().map(|r| r.epoch), latest, "pushed {pushed}");
+            for (index, epoch) in records {
+                assert_eq!(history.records[index].epoch, epoch, "pushed {pushed}");
+            }
+        }
+    }
+}
@@ -1,11 +1,15 @@
 mod bus;
 mod config;
+mod epoch_history;
 // mod hash;
 mod proof;
 mod treasury;
+mod vesting;
 
 pub use bus::*;
 pub use config::*;
+pub use epoch_history::*;
 // pub use hash::*;
 pub use proof::*;
 pub use treasury::*;
+pub use vesting::*;
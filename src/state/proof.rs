@@ -7,7 +7,7 @@ use crate::{
     utils::{AccountDiscriminator, Discriminator},
 };
 
-/// Proof accounts track a miner's current hash, claimable rewards, and lifetime stats.
+/// Proof accounts track a miner's current hash, claimable rewards, stake, and lifetime stats.
 /// Every miner is allowed one proof account which is required by the program to mine or claim rewards.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
@@ -15,9 +15,15 @@ pub struct Proof {
     /// The signer authorized to use this proof.
     pub authority: Pubkey,
 
-    /// The quantity of tokens this miner has staked or earned.
+    /// The signer authorized to submit hashes on behalf of the authority.
+    pub miner: Pubkey,
+
+    /// The quantity of tokens this miner has earned and may claim.
     pub balance: u64,
 
+    /// The quantity of tokens this miner has staked.
+    pub stake: u64,
+
     /// The current mining challenge.
     pub challenge: [u8; 32],
 
@@ -30,6 +36,19 @@ pub struct Proof {
     /// The last time stake was deposited into this account.
     pub last_stake_at: i64,
 
+    /// The stake-weighted average time at which the staked balance was deposited. Its distance
+    /// from the current time is the age of the stake.
+    pub stake_weighted_at: i64,
+
+    /// The quantity of staked tokens which are locked until the lock expires.
+    pub locked_stake: u64,
+
+    /// The lock tier of the locked stake, which determines its staking bonus.
+    pub lock_tier: u64,
+
+    /// The time at which the locked stake unlocks.
+    pub lock_expires_at: i64,
+
     /// The total lifetime hashes provided by this miner.
     pub total_hashes: u64,
 
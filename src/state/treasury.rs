@@ -8,12 +8,33 @@ use crate::{
 
 /// Treasury is a singleton account which manages all program wide variables.
 /// It is the mint authority for the Ore token and also the authority of the program-owned token account.
+/// It tracks lifetime totals of every token flow in and out of the program, so supply can be audited on chain.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
 pub struct Treasury {
     /// The bump of the treasury account PDA, for signing CPIs.
-    // TODO Is this needed if bump is const?
     pub bump: u64,
+
+    /// The rewards held by the treasury to fund mining once emissions have tapered off.
+    pub reward_pool: u64,
+
+    /// The lifetime quantity of tokens minted by reset, including reset bounties.
+    pub total_minted: u64,
+
+    /// The lifetime quantity of tokens burned by the early claim penalty.
+    pub total_burned: u64,
+
+    /// The lifetime quantity of tokens transferred to miners by claim.
+    pub total_claimed: u64,
+
+    /// The lifetime quantity of tokens deposited by stake.
+    pub total_staked: u64,
+
+    /// The lifetime quantity of tokens withdrawn by unstake.
+    pub total_unstaked: u64,
+
+    /// The lifetime quantity of tokens minted by upgrade.
+    pub total_upgraded: u64,
 }
 
 impl Discriminator for Treasury {
@@ -0,0 +1,176 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    impl_account_from_bytes, impl_to_bytes,
+    utils::{AccountDiscriminator, Discriminator},
+};
+
+/// Vesting accounts release a miner's vested rewards linearly over time. Every proof is allowed one
+/// vesting account, which holds a single stream that later deposits are blended into.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, Zeroable)]
+pub struct Vesting {
+    /// The signer authorized to withdraw vested tokens.
+    pub authority: Pubkey,
+
+    /// The proof whose rewards are vesting.
+    pub proof: Pubkey,
+
+    /// The bump of the vesting account PDA.
+    pub bump: u64,
+
+    /// The quantity of tokens which have vested and may be withdrawn.
+    pub unlocked: u64,
+
+    /// The quantity of tokens which had not yet vested at the last update.
+    pub locked: u64,
+
+    /// The last time the stream was updated.
+    pub updated_at: i64,
+
+    /// The time at which all locked tokens will have vested.
+    pub ends_at: i64,
+}
+
+impl Vesting {
+    /// Unlocks the tokens which have vested since the last update.
+    pub fn update(&mut self, now: i64) {
+        if now.le(&self.updated_at) {
+            return;
+        }
+        if now.ge(&self.ends_at) {
+            self.unlocked = self.unlocked.saturating_add(self.locked);
+            self.locked = 0;
+        } else {
+            let elapsed = now.saturating_sub(self.updated_at) as u128;
+            let remaining = self.ends_at.saturating_sub(self.updated_at) as u128;
+            let released = (self.locked as u128)
+                .saturating_mul(elapsed)
+                .saturating_div(remaining) as u64;
+            self.unlocked = self.unlocked.saturating_add(released);
+            self.locked = self.locked.saturating_sub(released);
+        }
+        self.updated_at = now;
+    }
+
+    /// Adds tokens to the stream. The end of the stream becomes the balance-weighted average of the
+    /// remaining duration of the locked tokens and the vesting duration of the new tokens.
+    pub fn deposit(&mut self, amount: u64, duration: i64, now: i64) {
+        self.update(now);
+        let locked = self.locked.saturating_add(amount);
+        if locked.eq(&0) {
+            return;
+        }
+        let remaining = self.ends_at.saturating_sub(now).max(0) as i128;
+        let weighted_duration = (self.locked as i128)
+            .saturating_mul(remaining)
+            .saturating_add((amount as i128).saturating_mul(duration.max(0) as i128))
+            .saturating_div(locked as i128);
+        self.locked = locked;
+        self.ends_at = now.saturating_add(weighted_duration as i64);
+    }
+}
+
+impl Discriminator for Vesting {
+    fn discriminator() -> AccountDiscriminator {
+        AccountDiscriminator::Vesting
+    }
+}
+
+impl_to_bytes!(Vesting);
+impl_account_from_bytes!(Vesting);
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use crate::{state::Vesting, ONE_DAY};
+
+    const NOW: i64 = 1_000_000;
+
+    /// A stream of 1000 tokens deposited now with a vesting duration of one day.
+    const STREAM: (u64, u64, i64, i64) = (0, 1000, NOW, NOW + ONE_DAY);
+
+    fn vesting((unlocked, locked, updated_at, ends_at): (u64, u64, i64, i64)) -> Vesting {
+        Vesting {
+            unlocked,
+            locked,
+            updated_at,
+            ends_at,
+            ..Vesting::zeroed()
+        }
+    }
+
+    #[test]
+    fn test_vesting_update() {
+        for (stream, now, expected) in [
+            // Tokens vest linearly over the stream
+            (
+                STREAM,
+                NOW + ONE_DAY / 4,
+                (250, 750, NOW + ONE_DAY / 4, NOW + ONE_DAY),
+            ),
+            (
+                STREAM,
+                NOW + ONE_DAY / 2,
+                (500, 500, NOW + ONE_DAY / 2, NOW + ONE_DAY),
+            ),
+            (
+                (250, 750, NOW + ONE_DAY / 4, NOW + ONE_DAY),
+                NOW + ONE_DAY / 2,
+                (500, 500, NOW + ONE_DAY / 2, NOW + ONE_DAY),
+            ),
+            // Every token has vested once the stream ends
+            (
+                STREAM,
+                NOW + ONE_DAY * 2,
+                (1000, 0, NOW + ONE_DAY * 2, NOW + ONE_DAY),
+            ),
+            // Updates from the past are ignored
+            (
+                (500, 500, NOW + ONE_DAY / 2, NOW + ONE_DAY),
+                NOW,
+                (500, 500, NOW + ONE_DAY / 2, NOW + ONE_DAY),
+            ),
+        ] {
+            let mut v = vesting(stream);
+            v.update(now);
+            assert_eq!(v, vesting(expected), "stream {stream:?}, now {now}");
+        }
+    }
+
+    #[test]
+    fn test_vesting_deposit() {
+        for (stream, amount, now, expected) in [
+            // A first deposit vests over the full duration
+            ((0, 0, NOW, NOW), 1000, NOW, STREAM),
+            // An empty deposit into an empty stream changes nothing
+            ((0, 0, NOW, NOW), 0, NOW, (0, 0, NOW, NOW)),
+            // A deposit mid-stream blends its duration with the remaining duration
+            (
+                STREAM,
+                1000,
+                NOW + ONE_DAY / 2,
+                (
+                    500,
+                    1500,
+                    NOW + ONE_DAY / 2,
+                    NOW + ONE_DAY / 2 + ONE_DAY * 5 / 6,
+                ),
+            ),
+            // A deposit after the stream ends starts a new stream
+            (
+                STREAM,
+                500,
+                NOW + ONE_DAY * 2,
+                (1000, 500, NOW + ONE_DAY * 2, NOW + ONE_DAY * 3),
+            ),
+        ] {
+            let mut v = vesting(stream);
+            v.deposit(amount, ONE_DAY, now);
+            assert_eq!(v, vesting(expected), "stream {stream:?}, amount {amount}");
+        }
+    }
+}
@@ -5,6 +5,13 @@ use solana_program::{
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::{
+    error::OreError,
+    state::{Bus, Config, Proof},
+    MINE_EVENT_VERSION, ONE_MINUTE, ONE_YEAR, STAKE_LOCK_BOOSTS, STAKE_LOCK_DURATIONS,
+    STAKE_RAMP_DURATION,
+};
+
 /// Creates a new pda
 #[inline(always)]
 pub(crate) fn create_pda<'a, 'info>(
@@ -73,12 +80,198 @@ pub(crate) fn create_pda<'a, 'info>(
     Ok(())
 }
 
+/// MineEvent is written to the transaction return data by every successful mine instruction.
+/// Indexers should decode it with `MineEvent::try_from_bytes` rather than parsing program logs.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 pub struct MineEvent {
+    /// The layout version of the event.
+    pub version: u64,
+
+    /// The epoch in which the hash was submitted.
+    pub epoch: u64,
+
+    /// The difficulty of the submitted hash.
     pub difficulty: u64,
+
+    /// The reward earned for the difficulty before staking and penalties.
+    pub reward_base: u64,
+
+    /// The bonus earned from the staking multiplier.
+    pub reward_staking: u64,
+
+    /// The reward forfeited for submitting before the spam tolerance window.
+    pub penalty_spam: u64,
+
+    /// The reward forfeited for submitting after the liveness tolerance window.
+    pub penalty_liveness: u64,
+
+    /// The ID of the bus that paid out the reward.
+    pub bus: u64,
+
+    /// The reward actually paid out, after being limited by the bus.
+    pub reward: u64,
+
+    /// The reward that would have been paid out if the bus had no limit.
+    pub reward_theoretical: u64,
+}
+
+impl MineEvent {
+    pub fn try_from_bytes(data: &[u8]) -> Result<&Self, ProgramError> {
+        let event =
+            bytemuck::try_from_bytes::<Self>(data).or(Err(ProgramError::InvalidArgument))?;
+        if event.version.ne(&MINE_EVENT_VERSION) {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(event)
+    }
+}
+
+/// An itemized breakdown of the rewards a hash would earn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RewardQuote {
+    /// The reward earned for the difficulty before staking and penalties.
+    pub reward_base: u64,
+
+    /// The bonus earned from the staking multiplier.
+    pub reward_staking: u64,
+
+    /// The reward forfeited for submitting before the spam tolerance window.
+    pub penalty_spam: u64,
+
+    /// The reward forfeited for submitting after the liveness tolerance window.
+    pub penalty_liveness: u64,
+
+    /// The reward that would be paid out if the bus had no limit.
+    pub reward_theoretical: u64,
+
+    /// The reward that would actually be paid out, after being limited by the bus.
     pub reward: u64,
-    pub timing: i64,
+}
+
+/// Calculates the rewards a proof would earn by submitting a hash of the given difficulty to the
+/// given bus at time `now`. This is the exact calculation used by the mine instruction, so clients
+/// may use it to decide whether a hash is worth submitting. Hashes below the minimum difficulty
+/// earn nothing. If the bus has not been topped up in the current epoch, its rewards should first
+/// be set to the config's bus rewards, as the mine instruction would.
+pub fn calculate_reward(
+    config: &Config,
+    proof: &Proof,
+    bus: &Bus,
+    difficulty: u32,
+    now: i64,
+) -> RewardQuote {
+    let mut quote = RewardQuote::default();
+    let min_difficulty = config.min_difficulty as u32;
+    if difficulty.lt(&min_difficulty) {
+        return quote;
+    }
+
+    // Calculate base reward rate
+    quote.reward_base = config
+        .base_reward_rate
+        .saturating_mul(2u64.saturating_pow(difficulty.saturating_sub(min_difficulty)));
+    let mut reward = quote.reward_base;
+
+    // Apply staking multiplier.
+    // The multiplier can range 1x to 2x, or up to 4x for stake locked at the highest tier. To receive the
+    // maximum multiplier, the staked balance must be greater than or equal to one year worth of rewards at
+    // the selected difficulty, and the stake must be at least as old as the stake ramp duration. Younger
+    // stake earns a proportional share.
+    let stake_age = now
+        .saturating_sub(proof.stake_weighted_at)
+        .clamp(0, STAKE_RAMP_DURATION);
+    if stake_age.gt(&0) && reward.gt(&0) {
+        let upper_bound = reward.saturating_mul(ONE_YEAR);
+        let stake = proof.stake.min(upper_bound);
+        let locked_stake = calculate_locked_stake(proof, now).min(stake);
+        let boost = STAKE_LOCK_BOOSTS
+            .get(proof.lock_tier as usize)
+            .copied()
+            .unwrap_or(STAKE_LOCK_BOOSTS[0]);
+        let weighted_stake = (stake.saturating_sub(locked_stake) as u128)
+            .saturating_mul(STAKE_LOCK_BOOSTS[0] as u128)
+            .saturating_add((locked_stake as u128).saturating_mul(boost as u128));
+        quote.reward_staking = weighted_stake
+            .saturating_mul(reward as u128)
+            .saturating_mul(stake_age as u128)
+            .saturating_div(upper_bound as u128)
+            .saturating_div(STAKE_RAMP_DURATION as u128)
+            .saturating_div(100) as u64;
+        reward = reward.saturating_add(quote.reward_staking);
+    }
+
+    // Apply spam penalty
+    let t_target = proof.last_hash_at.saturating_add(ONE_MINUTE);
+    let t_spam = t_target.saturating_sub(config.tolerance_spam);
+    if now.lt(&t_spam) {
+        quote.penalty_spam = reward;
+        reward = 0;
+    }
+
+    // Apply liveness penalty
+    let t_liveness = t_target.saturating_add(config.tolerance_liveness);
+    if now.gt(&t_liveness) {
+        quote.penalty_liveness = reward
+            .saturating_mul(now.saturating_sub(t_liveness) as u64)
+            .saturating_div(ONE_MINUTE as u64)
+            .min(reward);
+        reward = reward.saturating_sub(quote.penalty_liveness);
+    }
+
+    // Limit payout amount to whatever is left in the bus
+    quote.reward_theoretical = reward;
+    quote.reward = reward.min(bus.rewards);
+    quote
+}
+
+/// Returns the quantity of a proof's stake which is still locked at time `now`.
+pub fn calculate_locked_stake(proof: &Proof, now: i64) -> u64 {
+    if now.ge(&proof.lock_expires_at) {
+        return 0;
+    }
+    proof.locked_stake.min(proof.stake)
+}
+
+/// Adds a deposit of `amount` to a proof's stake lock at the given tier, clearing the lock first if
+/// it has expired. The deposit may not use a lower tier than an active lock, or it would earn the
+/// boost of the higher tier while only being locked for the duration of the lower one. The whole
+/// lock then expires one lock duration of its tier from `now`.
+pub fn lock_stake(
+    proof: &mut Proof,
+    amount: u64,
+    lock_tier: u64,
+    now: i64,
+) -> Result<(), OreError> {
+    let lock_duration = *STAKE_LOCK_DURATIONS
+        .get(lock_tier as usize)
+        .ok_or(OreError::StakeLockInvalid)?;
+    if now.ge(&proof.lock_expires_at) {
+        proof.locked_stake = 0;
+        proof.lock_tier = 0;
+    }
+    if lock_tier.lt(&proof.lock_tier) {
+        return Err(OreError::StakeLockInvalid);
+    }
+    proof.locked_stake = proof.locked_stake.saturating_add(amount);
+    proof.lock_tier = lock_tier;
+    proof.lock_expires_at = proof.lock_expires_at.max(now.saturating_add(lock_duration));
+    Ok(())
+}
+
+/// Calculates the stake-weighted deposit time of a staked balance after depositing `amount` at time
+/// `now`. The existing stake keeps its age, and the deposit is blended in with an age of zero.
+///
+/// new_weighted_at = (stake * weighted_at + amount * now) / (stake + amount)
+pub fn calculate_stake_weighted_at(stake: u64, weighted_at: i64, amount: u64, now: i64) -> i64 {
+    let total = (stake as i128).saturating_add(amount as i128);
+    if total.eq(&0) {
+        return now;
+    }
+    (stake as i128)
+        .saturating_mul(weighted_at.min(now) as i128)
+        .saturating_add((amount as i128).saturating_mul(now as i128))
+        .saturating_div(total) as i64
 }
 
 #[repr(u8)]
@@ -88,6 +281,8 @@ pub enum AccountDiscriminator {
     Config = 101,
     Proof = 102,
     Treasury = 103,
+    EpochHistory = 104,
+    Vesting = 105,
 }
 
 pub trait Discriminator {
@@ -152,3 +347,244 @@ macro_rules! impl_instruction_from_bytes {
         }
     };
 }
+
+impl_to_bytes!(MineEvent);
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use crate::{
+        error::OreError,
+        state::{Bus, Config, Proof},
+        utils::{
+            calculate_locked_stake, calculate_reward, calculate_stake_weighted_at, lock_stake,
+        },
+        ONE_DAY, ONE_MINUTE, ONE_YEAR, STAKE_LOCK_BOOSTS, STAKE_LOCK_DURATIONS,
+        STAKE_RAMP_DURATION,
+    };
+
+    const NOW: i64 = 1_000_000;
+
+    fn setup() -> (Config, Proof, Bus) {
+        let mut config = Config::zeroed();
+        config.base_reward_rate = 1000;
+        config.min_difficulty = 8;
+        config.tolerance_spam = 5;
+        config.tolerance_liveness = 5;
+        let mut proof = Proof::zeroed();
+        proof.last_hash_at = NOW - ONE_MINUTE;
+        proof.last_stake_at = NOW - STAKE_RAMP_DURATION;
+        proof.stake_weighted_at = NOW - STAKE_RAMP_DURATION;
+        let mut bus = Bus::zeroed();
+        bus.rewards = u64::MAX;
+        (config, proof, bus)
+    }
+
+    #[test]
+    fn test_calculate_reward_base() {
+        let (config, proof, bus) = setup();
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.reward_base, config.base_reward_rate);
+        assert_eq!(quote.reward, config.base_reward_rate);
+        assert_eq!(quote.reward_theoretical, config.base_reward_rate);
+    }
+
+    #[test]
+    fn test_calculate_reward_difficulty() {
+        let (config, proof, bus) = setup();
+        let quote = calculate_reward(&config, &proof, &bus, 11, NOW);
+        assert_eq!(quote.reward_base, config.base_reward_rate.saturating_mul(8));
+    }
+
+    #[test]
+    fn test_calculate_reward_too_easy() {
+        let (config, proof, bus) = setup();
+        let quote = calculate_reward(&config, &proof, &bus, 7, NOW);
+        assert_eq!(quote, Default::default());
+    }
+
+    #[test]
+    fn test_calculate_reward_staking_max() {
+        let (config, mut proof, bus) = setup();
+        proof.stake = u64::MAX;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.reward_staking, quote.reward_base);
+        assert_eq!(quote.reward, quote.reward_base.saturating_mul(2));
+    }
+
+    #[test]
+    fn test_calculate_reward_staking_partial() {
+        let (config, mut proof, bus) = setup();
+        proof.stake = config.base_reward_rate.saturating_mul(ONE_YEAR) / 2;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.reward_staking, quote.reward_base / 2);
+    }
+
+    #[test]
+    fn test_calculate_reward_staking_ramp() {
+        let (config, mut proof, bus) = setup();
+        proof.stake = u64::MAX;
+        let base = config.base_reward_rate;
+        let ramp = STAKE_RAMP_DURATION as u64;
+        for (stake_age, reward_staking) in [
+            (-ONE_DAY, 0),
+            (0, 0),
+            (STAKE_RAMP_DURATION / 2, base / 2),
+            (STAKE_RAMP_DURATION - 1, base * (ramp - 1) / ramp),
+            (STAKE_RAMP_DURATION, base),
+            (2 * STAKE_RAMP_DURATION, base),
+        ] {
+            proof.stake_weighted_at = NOW - stake_age;
+            let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+            assert_eq!(
+                quote.reward_staking, reward_staking,
+                "stake age {stake_age}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_reward_staking_locked_max() {
+        let (config, mut proof, bus) = setup();
+        proof.stake = u64::MAX;
+        proof.locked_stake = u64::MAX;
+        proof.lock_tier = 3;
+        proof.lock_expires_at = NOW + STAKE_LOCK_DURATIONS[3];
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(
+            quote.reward_staking,
+            quote.reward_base * STAKE_LOCK_BOOSTS[3] / 100
+        );
+    }
+
+    #[test]
+    fn test_calculate_reward_staking_locked_partial() {
+        let (config, mut proof, bus) = setup();
+        proof.stake = config.base_reward_rate.saturating_mul(ONE_YEAR);
+        proof.locked_stake = proof.stake / 2;
+        proof.lock_tier = 2;
+        proof.lock_expires_at = NOW + 1;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(
+            quote.reward_staking,
+            quote.reward_base * (STAKE_LOCK_BOOSTS[0] + STAKE_LOCK_BOOSTS[2]) / 200
+        );
+    }
+
+    #[test]
+    fn test_calculate_reward_staking_lock_expired() {
+        let (config, mut proof, bus) = setup();
+        proof.stake = u64::MAX;
+        proof.locked_stake = u64::MAX;
+        proof.lock_tier = 3;
+        proof.lock_expires_at = NOW;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.reward_staking, quote.reward_base);
+    }
+
+    #[test]
+    fn test_calculate_locked_stake() {
+        let mut proof = Proof::zeroed();
+        proof.stake = 1000;
+        proof.locked_stake = 400;
+        proof.lock_expires_at = NOW + ONE_DAY;
+        assert_eq!(calculate_locked_stake(&proof, NOW), 400);
+        assert_eq!(calculate_locked_stake(&proof, NOW + ONE_DAY), 0);
+    }
+
+    #[test]
+    fn test_lock_stake() {
+        let amount = 1000;
+        for (lock, lock_tier, expected) in [
+            // A first lock expires one lock duration from now
+            ((0, 0, 0), 1, Ok((amount, 1, NOW + STAKE_LOCK_DURATIONS[1]))),
+            // A deposit at the same tier restarts the lock
+            (
+                (500, 2, NOW + 1),
+                2,
+                Ok((500 + amount, 2, NOW + STAKE_LOCK_DURATIONS[2])),
+            ),
+            // A deposit at a higher tier raises the whole lock to that tier
+            (
+                (500, 1, NOW + 1),
+                3,
+                Ok((500 + amount, 3, NOW + STAKE_LOCK_DURATIONS[3])),
+            ),
+            // A deposit at a lower tier cannot join a higher tier lock
+            ((1, 3, NOW + 1), 1, Err(OreError::StakeLockInvalid)),
+            // An expired lock is replaced
+            (
+                (500, 3, NOW),
+                1,
+                Ok((amount, 1, NOW + STAKE_LOCK_DURATIONS[1])),
+            ),
+            // Tiers past the last lock duration are invalid
+            (
+                (0, 0, 0),
+                STAKE_LOCK_DURATIONS.len() as u64,
+                Err(OreError::StakeLockInvalid),
+            ),
+        ] {
+            let mut proof = Proof::zeroed();
+            (proof.locked_stake, proof.lock_tier, proof.lock_expires_at) = lock;
+            let res = lock_stake(&mut proof, amount, lock_tier, NOW)
+                .map(|_| (proof.locked_stake, proof.lock_tier, proof.lock_expires_at));
+            assert_eq!(res, expected, "lock {lock:?}, tier {lock_tier}");
+        }
+    }
+
+    #[test]
+    fn test_calculate_stake_weighted_at() {
+        for (stake, weighted_at, amount, expected) in [
+            // A first deposit starts with an age of zero
+            (0, 0, 1000, NOW),
+            // Doubling an aged stake halves its age rather than resetting it
+            (1000, NOW - 2 * ONE_DAY, 1000, NOW - ONE_DAY),
+            // A tiny deposit does not measurably change the age of a large stake
+            (1_000_000, NOW - ONE_DAY, 1, NOW - ONE_DAY),
+            // A weighted time in the future is treated as now
+            (1000, NOW + ONE_DAY, 1000, NOW),
+            (u64::MAX, 0, u64::MAX, NOW / 2),
+        ] {
+            let weighted_at = calculate_stake_weighted_at(stake, weighted_at, amount, NOW);
+            assert_eq!(weighted_at, expected, "stake {stake}, amount {amount}");
+        }
+    }
+
+    #[test]
+    fn test_calculate_reward_spam_penalty() {
+        let (config, mut proof, bus) = setup();
+        proof.last_hash_at = NOW;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.penalty_spam, quote.reward_base);
+        assert_eq!(quote.reward, 0);
+    }
+
+    #[test]
+    fn test_calculate_reward_liveness_penalty() {
+        let (config, mut proof, bus) = setup();
+        proof.last_hash_at = NOW - ONE_MINUTE - config.tolerance_liveness - ONE_MINUTE / 2;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.penalty_liveness, quote.reward_base / 2);
+        assert_eq!(quote.reward, quote.reward_base / 2);
+    }
+
+    #[test]
+    fn test_calculate_reward_liveness_penalty_max() {
+        let (config, mut proof, bus) = setup();
+        proof.last_hash_at = 0;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.penalty_liveness, quote.reward_base);
+        assert_eq!(quote.reward, 0);
+    }
+
+    #[test]
+    fn test_calculate_reward_bus_limit() {
+        let (config, proof, mut bus) = setup();
+        bus.rewards = 1;
+        let quote = calculate_reward(&config, &proof, &bus, 8, NOW);
+        assert_eq!(quote.reward_theoretical, config.base_reward_rate);
+        assert_eq!(quote.reward, 1);
+    }
+}
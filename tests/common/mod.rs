@@ -0,0 +1,152 @@
+#![allow(dead_code)]
+
+use bytemuck::Pod;
+use drillx::Solution;
+use ore::{
+    state::{Config, Proof},
+    utils::{AccountDeserialize, Discriminator},
+    CONFIG_ADDRESS, MINT_ADDRESS, PROOF, TOKEN_DECIMALS, TREASURY_ADDRESS,
+};
+use solana_program::{
+    clock::Clock, native_token::LAMPORTS_PER_SOL, program_option::COption, program_pack::Pack,
+    pubkey::Pubkey, rent::Rent, system_program,
+};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{AccountState, Mint};
+
+pub fn proof_address(authority: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PROOF, authority.as_ref()], &ore::id()).0
+}
+
+pub async fn get_config(context: &mut ProgramTestContext) -> Config {
+    let config_account = context
+        .banks_client
+        .get_account(CONFIG_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    *Config::try_from_bytes(&config_account.data).unwrap()
+}
+
+pub async fn get_proof(context: &mut ProgramTestContext, authority: Pubkey) -> Proof {
+    let proof_account = context
+        .banks_client
+        .get_account(proof_address(authority))
+        .await
+        .unwrap()
+        .unwrap();
+    *Proof::try_from_bytes(&proof_account.data).unwrap()
+}
+
+pub async fn get_token_balance(context: &mut ProgramTestContext, owner: Pubkey) -> u64 {
+    let token_account = context
+        .banks_client
+        .get_account(get_associated_token_address(&owner, &MINT_ADDRESS))
+        .await
+        .unwrap()
+        .unwrap();
+    spl_token::state::Account::unpack(&token_account.data)
+        .unwrap()
+        .amount
+}
+
+pub async fn set_clock(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+pub fn find_solution(challenge: &[u8; 32]) -> Solution {
+    for n in 0..u64::MAX {
+        let nonce = n.to_le_bytes();
+        if let Ok(hash) = drillx::hash(challenge, &nonce) {
+            return Solution::new(hash.d, nonce);
+        }
+    }
+    unreachable!()
+}
+
+/// Adds a system account with enough lamports to pay for transactions and rent.
+pub fn add_payer(program_test: &mut ProgramTest, address: Pubkey) {
+    program_test.add_account(
+        address,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Adds an Ore program account, prefixed with the discriminator of its type.
+pub fn add_ore_account<T: Discriminator + Pod>(
+    program_test: &mut ProgramTest,
+    address: Pubkey,
+    account: T,
+) {
+    let data = [
+        &(T::discriminator() as u64).to_le_bytes(),
+        bytemuck::bytes_of(&account),
+    ]
+    .concat();
+    program_test.add_account(
+        address,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: ore::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Adds the Ore associated token account of the given owner.
+pub fn add_token_account(program_test: &mut ProgramTest, owner: Pubkey, amount: u64) {
+    let mut data = [0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint: MINT_ADDRESS,
+        owner,
+        amount,
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    program_test.add_account(
+        get_associated_token_address(&owner, &MINT_ADDRESS),
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data: data.to_vec(),
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Adds the Ore mint, with the treasury as its mint authority.
+pub fn add_mint(program_test: &mut ProgramTest, supply: u64) {
+    let mut data = [0; Mint::LEN];
+    Mint {
+        mint_authority: COption::Some(TREASURY_ADDRESS),
+        supply,
+        decimals: TOKEN_DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    program_test.add_account(
+        MINT_ADDRESS,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data: data.to_vec(),
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
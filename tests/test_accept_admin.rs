@@ -0,0 +1,139 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::{accept_admin, cancel_admin, pause, update_admin},
+    state::Config,
+    utils::AccountDeserialize,
+    CONFIG_ADDRESS,
+};
+use solana_program::{hash::Hash, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_ore_account, add_payer};
+
+#[tokio::test]
+async fn test_accept_admin() {
+    // Setup
+    let (mut banks, admin, new_admin, _, blockhash) = setup_program_test_env().await;
+
+    // Submit update admin ix proposing the new admin
+    let ix = update_admin(admin.pubkey(), new_admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the admin is unchanged until the proposal is accepted
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.admin, admin.pubkey());
+    assert_eq!(config.pending_admin, new_admin.pubkey());
+
+    // Submit accept admin ix from the new admin
+    let ix = accept_admin(new_admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_admin.pubkey()),
+        &[&new_admin],
+        blockhash,
+    );
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert config state
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.admin, new_admin.pubkey());
+    assert_eq!(config.pending_admin, Pubkey::default());
+
+    // Submit pause ix from the old admin
+    let ix = pause(admin.pubkey(), 0);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_accept_admin_bad_signer() {
+    // Setup
+    let (mut banks, admin, new_admin, alt_payer, blockhash) = setup_program_test_env().await;
+
+    // Submit update admin ix proposing the new admin
+    let ix = update_admin(admin.pubkey(), new_admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit accept admin ixs from signers other than the proposed admin
+    for signer in [&alt_payer, &admin] {
+        let ix = accept_admin(signer.pubkey());
+        let tx =
+            Transaction::new_signed_with_payer(&[ix], Some(&signer.pubkey()), &[signer], blockhash);
+        let res = banks.process_transaction(tx).await;
+        assert!(res.is_err());
+    }
+
+    // Assert config state
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.admin, admin.pubkey());
+    assert_eq!(config.pending_admin, new_admin.pubkey());
+}
+
+#[tokio::test]
+async fn test_accept_admin_cancelled() {
+    // Setup
+    let (mut banks, admin, new_admin, _, blockhash) = setup_program_test_env().await;
+
+    // Submit update admin ix proposing the new admin
+    let ix = update_admin(admin.pubkey(), new_admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit cancel admin ix
+    let ix = cancel_admin(admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit accept admin ix from the formerly proposed admin
+    let ix = accept_admin(new_admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_admin.pubkey()),
+        &[&new_admin],
+        blockhash,
+    );
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert config state
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.admin, admin.pubkey());
+    assert_eq!(config.pending_admin, Pubkey::default());
+}
+
+async fn setup_program_test_env() -> (BanksClient, Keypair, Keypair, Keypair, Hash) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup admin, new admin, and alt payer
+    let admin = Keypair::new();
+    let new_admin = Keypair::new();
+    let alt_payer = Keypair::new();
+    for payer in [&admin, &new_admin, &alt_payer] {
+        add_payer(&mut program_test, payer.pubkey());
+    }
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.admin = admin.pubkey();
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    let (banks, _, blockhash) = program_test.start().await;
+    (banks, admin, new_admin, alt_payer, blockhash)
+}
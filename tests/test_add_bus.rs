@@ -0,0 +1,295 @@
+use bytemuck::Zeroable;
+use ore::{
+    bus_epoch_rewards,
+    instruction::{add_bus, mine, reset},
+    state::{Bus, Config, EpochHistory, Proof, Treasury},
+    utils::AccountDeserialize,
+    BUS_ADDRESSES, CONFIG_ADDRESS, EPOCH_HISTORY_ADDRESS, INITIAL_BUS_COUNT, MAX_BUS_COUNT,
+    MINT_ADDRESS, ONE_MINUTE, ONE_ORE, TREASURY_ADDRESS, TREASURY_BUMP,
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_token::state::Mint;
+
+mod common;
+use common::{
+    add_mint, add_ore_account, add_payer, add_token_account, find_solution, get_config, get_proof,
+    proof_address, set_clock,
+};
+
+const BUS_COUNT: u64 = INITIAL_BUS_COUNT as u64;
+const BUS_REWARDS: u64 = bus_epoch_rewards(ONE_MINUTE, BUS_COUNT);
+const BUS_MINED: u64 = BUS_REWARDS / 4;
+const EPOCH: u64 = 5;
+const LAST_RESET_AT: i64 = 1_700_000_000;
+const SUPPLY: u64 = ONE_ORE * 1_000;
+
+#[tokio::test]
+async fn test_add_bus_reset() {
+    // Setup
+    let (mut context, admin, _) = setup_program_test_env().await;
+
+    // Submit add bus ix mid-epoch
+    let ix = add_bus(admin.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit reset ix once the epoch has ended
+    set_clock(&mut context, LAST_RESET_AT + ONE_MINUTE).await;
+    let ix = reset(admin.pubkey(), BUS_COUNT + 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert only the rewards paid out by the funded busses were minted
+    let mint_account = context
+        .banks_client
+        .get_account(MINT_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint = Mint::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint.supply, SUPPLY + BUS_MINED * BUS_COUNT);
+
+    // Assert the new bus is funded from the next epoch
+    let config = get_config(&mut context).await;
+    assert_eq!(config.epoch, EPOCH + 1);
+    assert_eq!(config.bus_count, BUS_COUNT + 1);
+    assert_eq!(config.funded_bus_count, BUS_COUNT + 1);
+    assert_eq!(config.epoch_rewards, config.bus_rewards * (BUS_COUNT + 1));
+}
+
+#[tokio::test]
+async fn test_add_bus_mine_after_reset() {
+    // Setup
+    let (mut context, admin, _) = setup_program_test_env().await;
+    context.warp_to_slot(100).unwrap();
+    set_clock(&mut context, LAST_RESET_AT + 1).await;
+
+    // Submit add bus ix mid-epoch
+    let ix = add_bus(admin.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit mine ix against the new bus in the same epoch
+    let bus_address = BUS_ADDRESSES[BUS_COUNT as usize];
+    let proof = get_proof(&mut context, admin.pubkey()).await;
+    let solution = find_solution(&proof.challenge);
+    let ix = mine(admin.pubkey(), admin.pubkey(), bus_address, solution);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the new bus paid nothing
+    let proof = get_proof(&mut context, admin.pubkey()).await;
+    assert_eq!(proof.balance, 0);
+
+    // Submit reset ix once the epoch has ended
+    set_clock(&mut context, LAST_RESET_AT + ONE_MINUTE).await;
+    let ix = reset(admin.pubkey(), BUS_COUNT + 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit mine ix against the new bus in the next epoch
+    set_clock(&mut context, proof.last_hash_at + ONE_MINUTE).await;
+    let solution = find_solution(&proof.challenge);
+    let ix = mine(admin.pubkey(), admin.pubkey(), bus_address, solution);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the new bus was topped up and paid out
+    let config = get_config(&mut context).await;
+    let bus_account = context
+        .banks_client
+        .get_account(bus_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let bus = Bus::try_from_bytes(&bus_account.data).unwrap();
+    let proof = get_proof(&mut context, admin.pubkey()).await;
+    assert!(proof.balance.gt(&0));
+    assert_eq!(bus.epoch, EPOCH + 1);
+    assert_eq!(bus.rewards, config.bus_rewards - proof.balance);
+}
+
+#[tokio::test]
+async fn test_add_bus_not_admin() {
+    // Setup
+    let (mut context, _, alt_payer) = setup_program_test_env().await;
+
+    // Submit add bus ix from a signer other than the admin
+    let ix = add_bus(alt_payer.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alt_payer.pubkey()),
+        &[&alt_payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_add_bus_wrong_id() {
+    // Setup
+    let (mut context, admin, _) = setup_program_test_env().await;
+
+    // Submit add bus ix for an existing bus and for a bus past the next id
+    for id in [0, BUS_COUNT + 1] {
+        let ix = add_bus(admin.pubkey(), id);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            context.last_blockhash,
+        );
+        let res = context.banks_client.process_transaction(tx).await;
+        assert!(res.is_err());
+    }
+
+    // Submit add bus ix with a bus account which is not the bus PDA
+    let mut ix = add_bus(admin.pubkey(), BUS_COUNT);
+    ix.accounts[1].pubkey = Pubkey::new_unique();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_add_bus_max_count() {
+    // Setup
+    let (mut context, admin, _) = setup_program_test_env().await;
+
+    // Submit add bus ixs up to the max bus count
+    for id in BUS_COUNT..MAX_BUS_COUNT as u64 {
+        let ix = add_bus(admin.pubkey(), id);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            context.last_blockhash,
+        );
+        let res = context.banks_client.process_transaction(tx).await;
+        assert!(res.is_ok());
+    }
+
+    // Submit add bus ix past the max bus count
+    let ix = add_bus(admin.pubkey(), MAX_BUS_COUNT as u64);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert the bus count stopped at the max
+    let config = get_config(&mut context).await;
+    assert_eq!(config.bus_count, MAX_BUS_COUNT as u64);
+}
+
+async fn setup_program_test_env() -> (ProgramTestContext, Keypair, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup admin and alt payer
+    let admin = Keypair::new();
+    let alt_payer = Keypair::new();
+    for payer in [&admin, &alt_payer] {
+        add_payer(&mut program_test, payer.pubkey());
+        add_token_account(&mut program_test, payer.pubkey(), 0);
+    }
+
+    // Setup config midway through an epoch
+    let mut config = Config::zeroed();
+    config.admin = admin.pubkey();
+    config.base_reward_rate = 1000;
+    config.bus_count = BUS_COUNT;
+    config.funded_bus_count = BUS_COUNT;
+    config.epoch = EPOCH;
+    config.epoch_duration = ONE_MINUTE;
+    config.epoch_rewards = BUS_REWARDS * BUS_COUNT;
+    config.bus_rewards = BUS_REWARDS;
+    config.last_reset_at = LAST_RESET_AT;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup busses, each of which has paid out part of its rewards
+    for id in 0..BUS_COUNT {
+        let mut bus = Bus::zeroed();
+        bus.id = id;
+        bus.epoch = EPOCH;
+        bus.rewards = BUS_REWARDS - BUS_MINED;
+        bus.theoretical_rewards = BUS_MINED;
+        add_ore_account(&mut program_test, BUS_ADDRESSES[id as usize], bus);
+    }
+
+    // Setup the admin's proof, which last hashed one minute before the test starts
+    let mut proof = Proof::zeroed();
+    proof.authority = admin.pubkey();
+    proof.miner = admin.pubkey();
+    proof.last_hash_at = LAST_RESET_AT + 1 - ONE_MINUTE;
+    add_ore_account(&mut program_test, proof_address(admin.pubkey()), proof);
+
+    // Setup epoch history
+    add_ore_account(
+        &mut program_test,
+        EPOCH_HISTORY_ADDRESS,
+        EpochHistory::zeroed(),
+    );
+
+    // Setup treasury
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, SUPPLY);
+
+    // Setup mint
+    add_mint(&mut program_test, SUPPLY);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, LAST_RESET_AT + 1).await;
+    (context, admin, alt_payer)
+}
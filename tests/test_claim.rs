@@ -0,0 +1,165 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::claim,
+    state::{Config, Proof, Treasury},
+    utils::AccountDeserialize,
+    CONFIG_ADDRESS, MINT_ADDRESS, ONE_ORE, PAUSE_CLAIM, PAUSE_MINE, TREASURY_ADDRESS,
+    TREASURY_BUMP,
+};
+use solana_program::{hash::Hash, program_pack::Pack};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+mod common;
+use common::{add_mint, add_ore_account, add_payer, add_token_account, proof_address};
+
+const PROOF_BALANCE: u64 = ONE_ORE;
+
+#[tokio::test]
+async fn test_claim() {
+    // Setup
+    let (mut banks, alice, _, blockhash) = setup_program_test_env(0).await;
+
+    // Submit claim ix
+    let beneficiary = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let ix = claim(alice.pubkey(), beneficiary, PROOF_BALANCE);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&alice.pubkey()), &[&alice], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert proof state
+    let proof_account = banks
+        .get_account(proof_address(alice.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let proof = Proof::try_from_bytes(&proof_account.data).unwrap();
+    assert_eq!(proof.balance, 0);
+
+    // Assert beneficiary state
+    let beneficiary_account = banks.get_account(beneficiary).await.unwrap().unwrap();
+    let beneficiary = spl_token::state::Account::unpack(&beneficiary_account.data).unwrap();
+    assert_eq!(beneficiary.amount, PROOF_BALANCE);
+}
+
+#[tokio::test]
+async fn test_claim_other_proof() {
+    // Setup
+    let (mut banks, alice, bob, blockhash) = setup_program_test_env(0).await;
+
+    // Submit claim ix from bob against alice's proof
+    let beneficiary = get_associated_token_address(&bob.pubkey(), &MINT_ADDRESS);
+    let mut ix = claim(bob.pubkey(), beneficiary, PROOF_BALANCE);
+    ix.accounts[4].pubkey = proof_address(alice.pubkey());
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&bob.pubkey()), &[&bob], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert alice's proof was not drained
+    let proof_account = banks
+        .get_account(proof_address(alice.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let proof = Proof::try_from_bytes(&proof_account.data).unwrap();
+    assert_eq!(proof.balance, PROOF_BALANCE);
+
+    // Assert bob received nothing
+    let beneficiary_account = banks.get_account(beneficiary).await.unwrap().unwrap();
+    let beneficiary = spl_token::state::Account::unpack(&beneficiary_account.data).unwrap();
+    assert_eq!(beneficiary.amount, 0);
+}
+
+#[tokio::test]
+async fn test_claim_fake_proof() {
+    // Setup
+    let (mut banks, _, bob, blockhash) = setup_program_test_env(0).await;
+
+    // Submit claim ix with a proof account not owned by the program
+    let beneficiary = get_associated_token_address(&bob.pubkey(), &MINT_ADDRESS);
+    let mut ix = claim(bob.pubkey(), beneficiary, PROOF_BALANCE);
+    ix.accounts[4].pubkey = bob.pubkey();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&bob.pubkey()), &[&bob], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_claim_paused() {
+    // Setup
+    let (mut banks, alice, _, blockhash) = setup_program_test_env(PAUSE_CLAIM).await;
+
+    // Submit claim ix
+    let beneficiary = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let ix = claim(alice.pubkey(), beneficiary, PROOF_BALANCE);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&alice.pubkey()), &[&alice], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_claim_other_flow_paused() {
+    // Setup
+    let (mut banks, alice, _, blockhash) = setup_program_test_env(PAUSE_MINE).await;
+
+    // Assert claims are unaffected by an unrelated pause flag
+    let beneficiary = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let ix = claim(alice.pubkey(), beneficiary, PROOF_BALANCE);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&alice.pubkey()), &[&alice], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_claim_not_enough_accounts() {
+    // Setup
+    let (mut banks, alice, _, blockhash) = setup_program_test_env(0).await;
+
+    // Submit claim ix without a proof account
+    let beneficiary = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let mut ix = claim(alice.pubkey(), beneficiary, PROOF_BALANCE);
+    ix.accounts.remove(4);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&alice.pubkey()), &[&alice], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+async fn setup_program_test_env(paused: u64) -> (BanksClient, Keypair, Keypair, Hash) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup miners
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    for miner in [&alice, &bob] {
+        add_payer(&mut program_test, miner.pubkey());
+        add_token_account(&mut program_test, miner.pubkey(), 0);
+    }
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.paused = paused;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup alice's proof
+    let mut proof = Proof::zeroed();
+    proof.authority = alice.pubkey();
+    proof.miner = alice.pubkey();
+    proof.balance = PROOF_BALANCE;
+    add_ore_account(&mut program_test, proof_address(alice.pubkey()), proof);
+
+    // Setup treasury
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, PROOF_BALANCE);
+
+    // Setup mint
+    add_mint(&mut program_test, PROOF_BALANCE);
+
+    let (banks, _, blockhash) = program_test.start().await;
+    (banks, alice, bob, blockhash)
+}
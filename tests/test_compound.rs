@@ -0,0 +1,150 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::compound,
+    state::{Config, Proof, Treasury},
+    utils::AccountDeserialize,
+    CONFIG_ADDRESS, ONE_DAY, ONE_ORE, PAUSE_CLAIM, PAUSE_STAKE, TREASURY_ADDRESS, TREASURY_BUMP,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_ore_account, add_payer, get_proof, proof_address, set_clock};
+
+const BALANCE: u64 = ONE_ORE * 10;
+const NOW: i64 = 1_700_000_000;
+const STAKE: u64 = ONE_ORE * 5;
+const STAKE_WEIGHTED_AT: i64 = NOW - 2 * ONE_DAY;
+
+#[tokio::test]
+async fn test_compound() {
+    // Setup
+    let (mut context, alice, _) = setup_program_test_env(0).await;
+
+    // Submit compound ix for as much as is already staked
+    let ix = compound(alice.pubkey(), STAKE);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the rewards were moved into stake, halving its age
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.balance, BALANCE - STAKE);
+    assert_eq!(proof.stake, STAKE * 2);
+    assert_eq!(proof.stake_weighted_at, NOW - ONE_DAY);
+    assert_eq!(proof.last_stake_at, NOW);
+
+    // Assert the compounded amount was recorded as both claimed and staked
+    let treasury_account = context
+        .banks_client
+        .get_account(TREASURY_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury = Treasury::try_from_bytes(&treasury_account.data).unwrap();
+    assert_eq!(treasury.total_claimed, STAKE);
+    assert_eq!(treasury.total_staked, STAKE);
+}
+
+#[tokio::test]
+async fn test_compound_too_large() {
+    // Setup
+    let (mut context, alice, _) = setup_program_test_env(0).await;
+
+    // Submit compound ix for more than the claimable balance
+    let ix = compound(alice.pubkey(), BALANCE + 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_compound_paused() {
+    // Compounding is paused if either claims or staking are paused
+    for paused in [PAUSE_CLAIM, PAUSE_STAKE] {
+        // Setup
+        let (mut context, alice, _) = setup_program_test_env(paused).await;
+
+        // Submit compound ix
+        let ix = compound(alice.pubkey(), STAKE);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&alice.pubkey()),
+            &[&alice],
+            context.last_blockhash,
+        );
+        let res = context.banks_client.process_transaction(tx).await;
+        assert!(res.is_err());
+
+        // Assert the proof is untouched
+        let proof = get_proof(&mut context, alice.pubkey()).await;
+        assert_eq!(proof.balance, BALANCE);
+        assert_eq!(proof.stake, STAKE);
+    }
+}
+
+#[tokio::test]
+async fn test_compound_not_authority() {
+    // Setup
+    let (mut context, alice, bob) = setup_program_test_env(0).await;
+
+    // Submit compound ix from bob against alice's proof
+    let mut ix = compound(bob.pubkey(), STAKE);
+    ix.accounts[2].pubkey = proof_address(alice.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+async fn setup_program_test_env(paused: u64) -> (ProgramTestContext, Keypair, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup alice and bob
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    for payer in [&alice, &bob] {
+        add_payer(&mut program_test, payer.pubkey());
+    }
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.paused = paused;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup alice's proof, with claimable rewards and an aged stake
+    let mut proof = Proof::zeroed();
+    proof.authority = alice.pubkey();
+    proof.miner = alice.pubkey();
+    proof.balance = BALANCE;
+    proof.stake = STAKE;
+    proof.last_stake_at = STAKE_WEIGHTED_AT;
+    proof.stake_weighted_at = STAKE_WEIGHTED_AT;
+    add_ore_account(&mut program_test, proof_address(alice.pubkey()), proof);
+
+    // Setup treasury
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, NOW).await;
+    (context, alice, bob)
+}
@@ -1,3 +1,46 @@
+use ore::{instruction::initialize, CONFIG_ADDRESS, INITIALIZER_ADDRESS};
+use solana_program::{native_token::LAMPORTS_PER_SOL, system_program};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[tokio::test]
+async fn test_initialize_front_run() {
+    // Setup
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+    let attacker = Keypair::new();
+    assert_ne!(attacker.pubkey(), INITIALIZER_ADDRESS);
+    program_test.add_account(
+        attacker.pubkey(),
+        Account {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks, _, blockhash) = program_test.start().await;
+
+    // Submit initialize ix from a signer other than the initializer
+    let ix = initialize(attacker.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        blockhash,
+    );
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert config was not created
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap();
+    assert!(config_account.is_none());
+}
+
 // use mpl_token_metadata::{
 //     accounts::Metadata,
 //     types::{Key, TokenStandard},
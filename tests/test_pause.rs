@@ -0,0 +1,102 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::pause, state::Config, utils::AccountDeserialize, CONFIG_ADDRESS, PAUSE_ALL,
+    PAUSE_UPGRADE,
+};
+use solana_program::hash::Hash;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_ore_account, add_payer};
+
+#[tokio::test]
+async fn test_unpause() {
+    // Setup
+    let (mut banks, admin, _, blockhash) = setup_program_test_env().await;
+
+    // Submit unpause ix
+    let ix = pause(admin.pubkey(), 0);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert config state
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.paused, 0);
+}
+
+#[tokio::test]
+async fn test_pause_partial() {
+    // Setup
+    let (mut banks, admin, _, blockhash) = setup_program_test_env().await;
+
+    // Submit ix leaving only upgrades paused
+    let ix = pause(admin.pubkey(), PAUSE_UPGRADE);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert config state
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.paused, PAUSE_UPGRADE);
+}
+
+#[tokio::test]
+async fn test_pause_unknown_flags() {
+    // Setup
+    let (mut banks, admin, _, blockhash) = setup_program_test_env().await;
+
+    // Submit ix with a flag outside the known set
+    let ix = pause(admin.pubkey(), PAUSE_ALL + 1);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_unpause_bad_signer() {
+    // Setup
+    let (mut banks, _, alt_payer, blockhash) = setup_program_test_env().await;
+
+    // Submit unpause ix from a non-admin signer
+    let ix = pause(alt_payer.pubkey(), 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alt_payer.pubkey()),
+        &[&alt_payer],
+        blockhash,
+    );
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert program is still paused
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.paused, PAUSE_ALL);
+}
+
+async fn setup_program_test_env() -> (BanksClient, Keypair, Keypair, Hash) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup admin and alt payer
+    let admin = Keypair::new();
+    let alt_payer = Keypair::new();
+    for payer in [&admin, &alt_payer] {
+        add_payer(&mut program_test, payer.pubkey());
+    }
+
+    // Setup config as left by initialize
+    let mut config = Config::zeroed();
+    config.admin = admin.pubkey();
+    config.paused = PAUSE_ALL;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    let (banks, _, blockhash) = program_test.start().await;
+    (banks, admin, alt_payer, blockhash)
+}
@@ -0,0 +1,221 @@
+use bytemuck::Zeroable;
+use ore::{
+    bus_epoch_rewards,
+    instruction::reset,
+    state::{Bus, Config, EpochHistory, Treasury},
+    utils::AccountDeserialize,
+    BUS_ADDRESSES, CONFIG_ADDRESS, EPOCH_HISTORY_ADDRESS, INITIAL_BUS_COUNT, MAX_RESET_BOUNTY,
+    MAX_SUPPLY, MINT_ADDRESS, ONE_MINUTE, ONE_ORE, TREASURY_ADDRESS, TREASURY_BUMP,
+};
+use solana_program::program_pack::Pack;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_token::state::Mint;
+
+mod common;
+use common::{
+    add_mint, add_ore_account, add_payer, add_token_account, get_config, get_token_balance,
+    set_clock,
+};
+
+const BUS_COUNT: u64 = INITIAL_BUS_COUNT as u64;
+const BUS_REWARDS: u64 = bus_epoch_rewards(ONE_MINUTE, BUS_COUNT);
+const LAST_RESET_AT: i64 = 1_700_000_000;
+const REWARD_POOL: u64 = ONE_ORE * 1_000;
+const SUPPLY: u64 = ONE_ORE * 1_000;
+
+#[tokio::test]
+async fn test_reset_bounty_minted() {
+    // Setup
+    let bus_mined = BUS_REWARDS / 4;
+    let (mut context, signer) = setup_program_test_env(bus_mined, false).await;
+
+    // Submit reset ix
+    let ix = reset(signer.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[&signer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the bounty was minted to the signer
+    let mint = get_mint(&mut context).await;
+    assert_eq!(
+        get_token_balance(&mut context, signer.pubkey()).await,
+        MAX_RESET_BOUNTY
+    );
+    assert_eq!(
+        get_token_balance(&mut context, TREASURY_ADDRESS).await,
+        SUPPLY + bus_mined * BUS_COUNT
+    );
+    assert_eq!(
+        mint.supply,
+        SUPPLY + bus_mined * BUS_COUNT + MAX_RESET_BOUNTY
+    );
+}
+
+#[tokio::test]
+async fn test_reset_bounty_pooled() {
+    // Setup
+    let bus_mined = BUS_REWARDS / 4;
+    let (mut context, signer) = setup_program_test_env(bus_mined, true).await;
+
+    // Submit reset ix
+    let ix = reset(signer.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[&signer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the bounty was paid out of the reward pool without minting
+    let mint = get_mint(&mut context).await;
+    assert_eq!(
+        get_token_balance(&mut context, signer.pubkey()).await,
+        MAX_RESET_BOUNTY
+    );
+    assert_eq!(
+        get_token_balance(&mut context, TREASURY_ADDRESS).await,
+        REWARD_POOL + BUS_REWARDS * BUS_COUNT - MAX_RESET_BOUNTY
+    );
+    assert_eq!(mint.supply, MAX_SUPPLY);
+
+    // Assert the unmined rewards were returned to the pool before the bounty was paid
+    let config = get_config(&mut context).await;
+    let treasury_account = context
+        .banks_client
+        .get_account(TREASURY_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury = Treasury::try_from_bytes(&treasury_account.data).unwrap();
+    assert_eq!(
+        treasury.reward_pool + config.pooled_rewards,
+        REWARD_POOL + (BUS_REWARDS - bus_mined) * BUS_COUNT - MAX_RESET_BOUNTY
+    );
+}
+
+#[tokio::test]
+async fn test_reset_bounty_capped() {
+    // Setup busses with only one grain left each
+    let (mut context, signer) = setup_program_test_env(BUS_REWARDS - 1, false).await;
+
+    // Submit reset ix
+    let ix = reset(signer.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[&signer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the bounty was capped by the unmined rewards
+    assert_eq!(
+        get_token_balance(&mut context, signer.pubkey()).await,
+        BUS_COUNT
+    );
+}
+
+#[tokio::test]
+async fn test_reset_bounty_exhausted() {
+    // Setup busses which have paid out all of their rewards, in both modes
+    for pooled in [false, true] {
+        let (mut context, signer) = setup_program_test_env(BUS_REWARDS, pooled).await;
+
+        // Submit reset ix
+        let ix = reset(signer.pubkey(), BUS_COUNT);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[&signer],
+            context.last_blockhash,
+        );
+        let res = context.banks_client.process_transaction(tx).await;
+        assert!(res.is_ok());
+
+        // Assert no bounty was paid
+        assert_eq!(get_token_balance(&mut context, signer.pubkey()).await, 0);
+    }
+}
+
+async fn get_mint(context: &mut ProgramTestContext) -> Mint {
+    let mint_account = context
+        .banks_client
+        .get_account(MINT_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    Mint::unpack(&mint_account.data).unwrap()
+}
+
+/// Sets up a program whose epoch has just ended, with every bus having paid out `bus_mined`. If
+/// `pooled` is set, the supply has converged and the epoch was funded from the reward pool.
+async fn setup_program_test_env(bus_mined: u64, pooled: bool) -> (ProgramTestContext, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup signer
+    let signer = Keypair::new();
+    add_payer(&mut program_test, signer.pubkey());
+    add_token_account(&mut program_test, signer.pubkey(), 0);
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.base_reward_rate = 1000;
+    config.bus_count = BUS_COUNT;
+    config.funded_bus_count = BUS_COUNT;
+    config.epoch_duration = ONE_MINUTE;
+    config.epoch_rewards = BUS_REWARDS * BUS_COUNT;
+    config.bus_rewards = BUS_REWARDS;
+    config.last_reset_at = LAST_RESET_AT;
+    config.reset_bounty = MAX_RESET_BOUNTY;
+    if pooled {
+        config.pooled_rewards = config.epoch_rewards;
+    }
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup busses
+    for id in 0..BUS_COUNT {
+        let mut bus = Bus::zeroed();
+        bus.id = id;
+        bus.rewards = BUS_REWARDS - bus_mined;
+        bus.theoretical_rewards = bus_mined;
+        add_ore_account(&mut program_test, BUS_ADDRESSES[id as usize], bus);
+    }
+
+    // Setup epoch history
+    add_ore_account(
+        &mut program_test,
+        EPOCH_HISTORY_ADDRESS,
+        EpochHistory::zeroed(),
+    );
+
+    // Setup treasury, which holds the reward pool and the rewards reserved for the epoch
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    let (supply, treasury_balance) = if pooled {
+        treasury.reward_pool = REWARD_POOL;
+        (MAX_SUPPLY, REWARD_POOL + config.pooled_rewards)
+    } else {
+        (SUPPLY, SUPPLY)
+    };
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, treasury_balance);
+
+    // Setup mint
+    add_mint(&mut program_test, supply);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, LAST_RESET_AT + ONE_MINUTE).await;
+    (context, signer)
+}
@@ -0,0 +1,207 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::{stake, unstake},
+    state::{Config, Proof, Treasury},
+    utils::AccountDeserialize,
+    CONFIG_ADDRESS, MINT_ADDRESS, ONE_ORE, TREASURY_ADDRESS, TREASURY_BUMP, UNSTAKE_COOLDOWN,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+mod common;
+use common::{
+    add_mint, add_ore_account, add_payer, add_token_account, get_proof, get_token_balance,
+    proof_address, set_clock,
+};
+
+const AMOUNT: u64 = ONE_ORE;
+const BALANCE: u64 = ONE_ORE * 10;
+const NOW: i64 = 1_700_000_000;
+const STAKE: u64 = ONE_ORE * 5;
+
+#[tokio::test]
+async fn test_stake_sponsored() {
+    // Setup
+    let (mut context, alice, sponsor) = setup_program_test_env().await;
+
+    // Submit stake ix from the sponsor into alice's proof
+    let sponsor_tokens = get_associated_token_address(&sponsor.pubkey(), &MINT_ADDRESS);
+    let ix = stake(sponsor.pubkey(), alice.pubkey(), sponsor_tokens, AMOUNT, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sponsor.pubkey()),
+        &[&sponsor],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the deposit was credited to alice's proof without restarting her cooldown
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.authority, alice.pubkey());
+    assert_eq!(proof.stake, STAKE + AMOUNT);
+    assert_eq!(proof.last_stake_at, NOW - UNSTAKE_COOLDOWN);
+    assert_eq!(
+        get_token_balance(&mut context, sponsor.pubkey()).await,
+        BALANCE - AMOUNT
+    );
+    let treasury_account = context
+        .banks_client
+        .get_account(TREASURY_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury = Treasury::try_from_bytes(&treasury_account.data).unwrap();
+    assert_eq!(treasury.total_staked, AMOUNT);
+
+    // Submit unstake ix from alice for all of her stake
+    let alice_tokens = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let ix = unstake(alice.pubkey(), alice_tokens, STAKE + AMOUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert alice withdrew the sponsored stake
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.stake, 0);
+    assert_eq!(
+        get_token_balance(&mut context, alice.pubkey()).await,
+        BALANCE + STAKE + AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_stake_sponsored_dust() {
+    // Setup
+    let (mut context, alice, sponsor) = setup_program_test_env().await;
+
+    // Submit stake ix from the sponsor with an empty deposit
+    let sponsor_tokens = get_associated_token_address(&sponsor.pubkey(), &MINT_ADDRESS);
+    let ix = stake(sponsor.pubkey(), alice.pubkey(), sponsor_tokens, 0, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sponsor.pubkey()),
+        &[&sponsor],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Submit stake ix from the sponsor with a dust deposit
+    let ix = stake(sponsor.pubkey(), alice.pubkey(), sponsor_tokens, 1, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sponsor.pubkey()),
+        &[&sponsor],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit unstake ix from alice right after the dust deposit
+    let alice_tokens = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let ix = unstake(alice.pubkey(), alice_tokens, STAKE);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert alice withdrew her stake, leaving only the dust
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.stake, 1);
+    assert_eq!(
+        get_token_balance(&mut context, alice.pubkey()).await,
+        BALANCE + STAKE
+    );
+}
+
+#[tokio::test]
+async fn test_stake_sponsored_lock() {
+    // Setup
+    let (mut context, alice, sponsor) = setup_program_test_env().await;
+
+    // Submit stake ix from the sponsor into alice's proof with a lock
+    let sponsor_tokens = get_associated_token_address(&sponsor.pubkey(), &MINT_ADDRESS);
+    let ix = stake(sponsor.pubkey(), alice.pubkey(), sponsor_tokens, AMOUNT, 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sponsor.pubkey()),
+        &[&sponsor],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_stake_sponsored_unstake() {
+    // Setup
+    let (mut context, alice, sponsor) = setup_program_test_env().await;
+
+    // Submit unstake ix from the sponsor against alice's proof
+    let sponsor_tokens = get_associated_token_address(&sponsor.pubkey(), &MINT_ADDRESS);
+    let mut ix = unstake(sponsor.pubkey(), sponsor_tokens, STAKE);
+    ix.accounts[3].pubkey = proof_address(alice.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sponsor.pubkey()),
+        &[&sponsor],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert alice's stake is untouched
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.stake, STAKE);
+}
+
+async fn setup_program_test_env() -> (ProgramTestContext, Keypair, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup alice and a sponsor
+    let alice = Keypair::new();
+    let sponsor = Keypair::new();
+    for payer in [&alice, &sponsor] {
+        add_payer(&mut program_test, payer.pubkey());
+        add_token_account(&mut program_test, payer.pubkey(), BALANCE);
+    }
+
+    // Setup config
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, Config::zeroed());
+
+    // Setup alice's proof, whose stake has passed the cooldown
+    let mut proof = Proof::zeroed();
+    proof.authority = alice.pubkey();
+    proof.miner = alice.pubkey();
+    proof.stake = STAKE;
+    proof.last_stake_at = NOW - UNSTAKE_COOLDOWN;
+    proof.stake_weighted_at = NOW - UNSTAKE_COOLDOWN;
+    add_ore_account(&mut program_test, proof_address(alice.pubkey()), proof);
+
+    // Setup treasury, which holds alice's stake
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, STAKE);
+
+    // Setup mint
+    add_mint(&mut program_test, BALANCE * 2 + STAKE);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, NOW).await;
+    (context, alice, sponsor)
+}
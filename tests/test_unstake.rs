@@ -0,0 +1,199 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::unstake,
+    state::{Config, Proof, Treasury},
+    utils::AccountDeserialize,
+    CONFIG_ADDRESS, MINT_ADDRESS, ONE_DAY, ONE_ORE, TREASURY_ADDRESS, TREASURY_BUMP,
+    UNSTAKE_COOLDOWN,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+mod common;
+use common::{
+    add_mint, add_ore_account, add_payer, add_token_account, get_proof, get_token_balance,
+    proof_address, set_clock,
+};
+
+const LOCKED_STAKE: u64 = ONE_ORE * 2;
+const NOW: i64 = 1_700_000_000;
+const STAKE: u64 = ONE_ORE * 5;
+const STAKE_WEIGHTED_AT: i64 = NOW - 2 * ONE_DAY;
+
+#[tokio::test]
+async fn test_unstake() {
+    // Setup
+    let (mut context, payer) = setup_program_test_env(0).await;
+
+    // Submit unstake ix for part of the stake
+    let beneficiary = get_associated_token_address(&payer.pubkey(), &MINT_ADDRESS);
+    let ix = unstake(payer.pubkey(), beneficiary, STAKE / 2);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the remaining stake kept its age
+    let proof = get_proof(&mut context, payer.pubkey()).await;
+    assert_eq!(proof.stake, STAKE - STAKE / 2);
+    assert_eq!(proof.stake_weighted_at, STAKE_WEIGHTED_AT);
+    assert_eq!(proof.last_stake_at, NOW - UNSTAKE_COOLDOWN);
+
+    // Assert the tokens were transferred and recorded
+    assert_eq!(
+        get_token_balance(&mut context, payer.pubkey()).await,
+        STAKE / 2
+    );
+    let treasury_account = context
+        .banks_client
+        .get_account(TREASURY_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury = Treasury::try_from_bytes(&treasury_account.data).unwrap();
+    assert_eq!(treasury.total_unstaked, STAKE / 2);
+}
+
+#[tokio::test]
+async fn test_unstake_too_large() {
+    // Setup
+    let (mut context, payer) = setup_program_test_env(0).await;
+
+    // Submit unstake ix for more than the staked balance
+    let beneficiary = get_associated_token_address(&payer.pubkey(), &MINT_ADDRESS);
+    let ix = unstake(payer.pubkey(), beneficiary, STAKE + 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_unstake_cooldown() {
+    // Setup
+    let (mut context, payer) = setup_program_test_env(0).await;
+
+    // Submit unstake ix one second before the cooldown elapses
+    set_clock(&mut context, NOW - 1).await;
+    let beneficiary = get_associated_token_address(&payer.pubkey(), &MINT_ADDRESS);
+    let ix = unstake(payer.pubkey(), beneficiary, STAKE);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Submit unstake ix once the cooldown has elapsed
+    set_clock(&mut context, NOW).await;
+    let ix = unstake(payer.pubkey(), beneficiary, STAKE);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_unstake_locked() {
+    // Setup
+    let (mut context, payer) = setup_program_test_env(LOCKED_STAKE).await;
+
+    // Submit unstake ix for all of the unlocked stake
+    let beneficiary = get_associated_token_address(&payer.pubkey(), &MINT_ADDRESS);
+    let ix = unstake(payer.pubkey(), beneficiary, STAKE - LOCKED_STAKE);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Submit unstake ix which would dip below the locked stake
+    let ix = unstake(payer.pubkey(), beneficiary, 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Submit unstake ix once the lock has expired
+    set_clock(&mut context, NOW + ONE_DAY).await;
+    let ix = unstake(payer.pubkey(), beneficiary, LOCKED_STAKE);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert all of the stake was withdrawn
+    let proof = get_proof(&mut context, payer.pubkey()).await;
+    assert_eq!(proof.stake, 0);
+    assert_eq!(get_token_balance(&mut context, payer.pubkey()).await, STAKE);
+}
+
+/// Sets up a proof whose last deposit was exactly one cooldown ago, with `locked_stake` of its stake
+/// locked for one more day.
+async fn setup_program_test_env(locked_stake: u64) -> (ProgramTestContext, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup payer
+    let payer = Keypair::new();
+    add_payer(&mut program_test, payer.pubkey());
+    add_token_account(&mut program_test, payer.pubkey(), 0);
+
+    // Setup config
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, Config::zeroed());
+
+    // Setup proof
+    let mut proof = Proof::zeroed();
+    proof.authority = payer.pubkey();
+    proof.miner = payer.pubkey();
+    proof.stake = STAKE;
+    proof.last_stake_at = NOW - UNSTAKE_COOLDOWN;
+    proof.stake_weighted_at = STAKE_WEIGHTED_AT;
+    if locked_stake.gt(&0) {
+        proof.locked_stake = locked_stake;
+        proof.lock_tier = 1;
+        proof.lock_expires_at = NOW + ONE_DAY;
+    }
+    add_ore_account(&mut program_test, proof_address(payer.pubkey()), proof);
+
+    // Setup treasury, which holds the stake
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, STAKE);
+
+    // Setup mint
+    add_mint(&mut program_test, STAKE);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, NOW).await;
+    (context, payer)
+}
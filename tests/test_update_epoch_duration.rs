@@ -0,0 +1,159 @@
+use bytemuck::Zeroable;
+use ore::{
+    bus_epoch_rewards,
+    instruction::{reset, update_epoch_duration},
+    state::{Bus, Config, EpochHistory, Treasury},
+    BUS_ADDRESSES, CONFIG_ADDRESS, EPOCH_HISTORY_ADDRESS, INITIAL_BUS_COUNT, MAX_EPOCH_DURATION,
+    MIN_EPOCH_DURATION, ONE_MINUTE, TREASURY_ADDRESS, TREASURY_BUMP,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_mint, add_ore_account, add_payer, add_token_account, get_config, set_clock};
+
+const BUS_COUNT: u64 = INITIAL_BUS_COUNT as u64;
+const BUS_REWARDS: u64 = bus_epoch_rewards(MAX_EPOCH_DURATION, BUS_COUNT);
+const LAST_RESET_AT: i64 = 1_700_000_000;
+
+#[tokio::test]
+async fn test_update_epoch_duration() {
+    // Setup an epoch which has just started
+    let (mut context, admin) = setup_program_test_env().await;
+
+    // Submit update epoch duration ix shortening the epoch
+    let ix = update_epoch_duration(admin.pubkey(), MIN_EPOCH_DURATION as u64);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the current epoch keeps its duration
+    let config = get_config(&mut context).await;
+    assert_eq!(config.epoch_duration, MAX_EPOCH_DURATION);
+    assert_eq!(config.pending_epoch_duration, MIN_EPOCH_DURATION);
+
+    // Submit reset ix once the shortened duration has passed
+    set_clock(&mut context, LAST_RESET_AT + MIN_EPOCH_DURATION).await;
+    let ix = reset(admin.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the epoch did not end early
+    let config = get_config(&mut context).await;
+    assert_eq!(config.last_reset_at, LAST_RESET_AT);
+    assert_eq!(config.bus_rewards, BUS_REWARDS);
+
+    // Submit reset ix once the current epoch has ended
+    set_clock(&mut context, LAST_RESET_AT + MAX_EPOCH_DURATION).await;
+    let ix = reset(admin.pubkey(), BUS_COUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the next epoch was allocated for the new duration
+    let config = get_config(&mut context).await;
+    assert_eq!(config.last_reset_at, LAST_RESET_AT + MAX_EPOCH_DURATION);
+    assert_eq!(config.epoch_duration, MIN_EPOCH_DURATION);
+    assert_eq!(config.pending_epoch_duration, 0);
+    assert_eq!(
+        config.bus_rewards,
+        bus_epoch_rewards(MIN_EPOCH_DURATION, BUS_COUNT)
+    );
+}
+
+#[tokio::test]
+async fn test_update_epoch_duration_out_of_bounds() {
+    // Setup
+    let (mut context, admin) = setup_program_test_env().await;
+
+    // Submit update epoch duration ixs outside the bounds or not in whole minutes
+    for epoch_duration in [
+        MIN_EPOCH_DURATION - ONE_MINUTE,
+        MAX_EPOCH_DURATION + ONE_MINUTE,
+        MIN_EPOCH_DURATION + 1,
+    ] {
+        let ix = update_epoch_duration(admin.pubkey(), epoch_duration as u64);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            context.last_blockhash,
+        );
+        let res = context.banks_client.process_transaction(tx).await;
+        assert!(res.is_err());
+    }
+
+    // Assert no duration is pending
+    let config = get_config(&mut context).await;
+    assert_eq!(config.pending_epoch_duration, 0);
+}
+
+/// Sets up a program whose five minute epoch started one second ago, with nothing mined and nothing
+/// minted, so the next allocation is not tapered.
+async fn setup_program_test_env() -> (ProgramTestContext, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup admin
+    let admin = Keypair::new();
+    add_payer(&mut program_test, admin.pubkey());
+    add_token_account(&mut program_test, admin.pubkey(), 0);
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.admin = admin.pubkey();
+    config.base_reward_rate = 1000;
+    config.bus_count = BUS_COUNT;
+    config.funded_bus_count = BUS_COUNT;
+    config.epoch_duration = MAX_EPOCH_DURATION;
+    config.epoch_rewards = BUS_REWARDS * BUS_COUNT;
+    config.bus_rewards = BUS_REWARDS;
+    config.last_reset_at = LAST_RESET_AT;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup busses
+    for id in 0..BUS_COUNT {
+        let mut bus = Bus::zeroed();
+        bus.id = id;
+        bus.rewards = BUS_REWARDS;
+        add_ore_account(&mut program_test, BUS_ADDRESSES[id as usize], bus);
+    }
+
+    // Setup epoch history
+    add_ore_account(
+        &mut program_test,
+        EPOCH_HISTORY_ADDRESS,
+        EpochHistory::zeroed(),
+    );
+
+    // Setup treasury
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, 0);
+
+    // Setup mint
+    add_mint(&mut program_test, 0);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, LAST_RESET_AT + 1).await;
+    (context, admin)
+}
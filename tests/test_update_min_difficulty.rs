@@ -0,0 +1,89 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::update_min_difficulty, state::Config, utils::AccountDeserialize, CONFIG_ADDRESS,
+    INITIAL_MIN_DIFFICULTY, MIN_DIFFICULTY_UPPER_BOUND,
+};
+use solana_program::hash::Hash;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_ore_account, add_payer};
+
+#[tokio::test]
+async fn test_update_min_difficulty() {
+    // Setup
+    let (mut banks, admin, _, blockhash) = setup_program_test_env().await;
+
+    // Submit update min difficulty ix
+    let ix = update_min_difficulty(admin.pubkey(), 12);
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert config state
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.min_difficulty, 12);
+    assert_eq!(config.admin, admin.pubkey());
+}
+
+#[tokio::test]
+async fn test_update_min_difficulty_out_of_bounds() {
+    // Setup
+    let (mut banks, admin, _, blockhash) = setup_program_test_env().await;
+
+    // Assert zero and values above the upper bound are rejected
+    for min_difficulty in [0, MIN_DIFFICULTY_UPPER_BOUND as u64 + 1] {
+        let ix = update_min_difficulty(admin.pubkey(), min_difficulty);
+        let tx =
+            Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin], blockhash);
+        let res = banks.process_transaction(tx).await;
+        assert!(res.is_err());
+    }
+
+    // Assert config state is unchanged
+    let config_account = banks.get_account(CONFIG_ADDRESS).await.unwrap().unwrap();
+    let config = Config::try_from_bytes(&config_account.data).unwrap();
+    assert_eq!(config.min_difficulty, INITIAL_MIN_DIFFICULTY as u64);
+}
+
+#[tokio::test]
+async fn test_update_min_difficulty_bad_signer() {
+    // Setup
+    let (mut banks, _, alt_payer, blockhash) = setup_program_test_env().await;
+
+    // Submit ix from a non-admin signer
+    let ix = update_min_difficulty(alt_payer.pubkey(), 12);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alt_payer.pubkey()),
+        &[&alt_payer],
+        blockhash,
+    );
+    let res = banks.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+async fn setup_program_test_env() -> (BanksClient, Keypair, Keypair, Hash) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup admin and alt payer
+    let admin = Keypair::new();
+    let alt_payer = Keypair::new();
+    for payer in [&admin, &alt_payer] {
+        add_payer(&mut program_test, payer.pubkey());
+    }
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.admin = admin.pubkey();
+    config.min_difficulty = INITIAL_MIN_DIFFICULTY as u64;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    let (banks, _, blockhash) = program_test.start().await;
+    (banks, admin, alt_payer, blockhash)
+}
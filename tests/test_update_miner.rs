@@ -0,0 +1,169 @@
+use bytemuck::Zeroable;
+use ore::{
+    bus_epoch_rewards,
+    instruction::{mine, update_miner},
+    state::{Bus, Config, Proof},
+    BUS_ADDRESSES, CONFIG_ADDRESS, INITIAL_BUS_COUNT, ONE_MINUTE,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_ore_account, add_payer, find_solution, get_proof, proof_address, set_clock};
+
+const BUS_REWARDS: u64 = bus_epoch_rewards(ONE_MINUTE, INITIAL_BUS_COUNT as u64);
+const EPOCH: u64 = 1;
+const NOW: i64 = 1_700_000_000;
+
+#[tokio::test]
+async fn test_update_miner_delegated_mining() {
+    // Setup
+    let (mut context, alice, miner) = setup_program_test_env().await;
+
+    // Submit update miner ix from the proof authority
+    let ix = update_miner(alice.pubkey(), miner.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the miner was delegated
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.authority, alice.pubkey());
+    assert_eq!(proof.miner, miner.pubkey());
+
+    // Submit mine ix from the authority, which is no longer the miner
+    let solution = find_solution(&proof.challenge);
+    let ix = mine(alice.pubkey(), alice.pubkey(), BUS_ADDRESSES[0], solution);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Submit mine ix from the delegated miner
+    let ix = mine(miner.pubkey(), alice.pubkey(), BUS_ADDRESSES[0], solution);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&miner.pubkey()),
+        &[&miner],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the rewards were credited to alice's proof
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.total_hashes, 1);
+    assert!(proof.balance.gt(&0));
+}
+
+#[tokio::test]
+async fn test_update_miner_revoked() {
+    // Setup
+    let (mut context, alice, miner) = setup_program_test_env().await;
+
+    // Submit update miner ixs delegating to the miner and then back to alice
+    for new_miner in [miner.pubkey(), alice.pubkey()] {
+        let ix = update_miner(alice.pubkey(), new_miner);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&alice.pubkey()),
+            &[&alice],
+            context.last_blockhash,
+        );
+        let res = context.banks_client.process_transaction(tx).await;
+        assert!(res.is_ok());
+    }
+
+    // Submit mine ix from the old miner
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    let solution = find_solution(&proof.challenge);
+    let ix = mine(miner.pubkey(), alice.pubkey(), BUS_ADDRESSES[0], solution);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&miner.pubkey()),
+        &[&miner],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert the proof is untouched
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.total_hashes, 0);
+}
+
+#[tokio::test]
+async fn test_update_miner_not_authority() {
+    // Setup
+    let (mut context, alice, miner) = setup_program_test_env().await;
+
+    // Submit update miner ix from the miner against alice's proof
+    let mut ix = update_miner(miner.pubkey(), miner.pubkey());
+    ix.accounts[1].pubkey = proof_address(alice.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&miner.pubkey()),
+        &[&miner],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert the miner is unchanged
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.miner, alice.pubkey());
+}
+
+async fn setup_program_test_env() -> (ProgramTestContext, Keypair, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup alice and a separate miner key
+    let alice = Keypair::new();
+    let miner = Keypair::new();
+    for payer in [&alice, &miner] {
+        add_payer(&mut program_test, payer.pubkey());
+    }
+
+    // Setup config at the start of an epoch
+    let mut config = Config::zeroed();
+    config.base_reward_rate = 1000;
+    config.bus_count = INITIAL_BUS_COUNT as u64;
+    config.funded_bus_count = INITIAL_BUS_COUNT as u64;
+    config.epoch = EPOCH;
+    config.epoch_duration = ONE_MINUTE;
+    config.bus_rewards = BUS_REWARDS;
+    config.epoch_rewards = BUS_REWARDS * INITIAL_BUS_COUNT as u64;
+    config.last_reset_at = NOW;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup bus
+    let mut bus = Bus::zeroed();
+    bus.epoch = EPOCH;
+    bus.rewards = BUS_REWARDS;
+    add_ore_account(&mut program_test, BUS_ADDRESSES[0], bus);
+
+    // Setup alice's proof, which is mined by alice and last hashed one minute ago
+    let mut proof = Proof::zeroed();
+    proof.authority = alice.pubkey();
+    proof.miner = alice.pubkey();
+    proof.last_hash_at = NOW - ONE_MINUTE;
+    add_ore_account(&mut program_test, proof_address(alice.pubkey()), proof);
+
+    // Warp ahead so the slot hashes sysvar is populated
+    let mut context = program_test.start_with_context().await;
+    context.warp_to_slot(100).unwrap();
+    set_clock(&mut context, NOW).await;
+    (context, alice, miner)
+}
@@ -0,0 +1,254 @@
+use bytemuck::Zeroable;
+use ore::{
+    instruction::{vest, withdraw},
+    state::{Config, Proof, Treasury, Vesting},
+    utils::AccountDeserialize,
+    CONFIG_ADDRESS, MINT_ADDRESS, ONE_DAY, ONE_ORE, TREASURY_ADDRESS, TREASURY_BUMP, VESTING,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+mod common;
+use common::{
+    add_ore_account, add_payer, add_token_account, get_proof, get_token_balance, proof_address,
+    set_clock,
+};
+
+const AMOUNT: u64 = ONE_ORE * 4;
+const BALANCE: u64 = ONE_ORE * 10;
+const FAKE_VESTING_ADDRESS: Pubkey = Pubkey::new_from_array([1; 32]);
+const NOW: i64 = 1_700_000_000;
+const OTHER_PROOF_ADDRESS: Pubkey = Pubkey::new_from_array([2; 32]);
+
+#[tokio::test]
+async fn test_vest() {
+    // Setup
+    let (mut context, alice) = setup_program_test_env().await;
+
+    // Submit vest ix
+    let ix = vest(alice.pubkey(), AMOUNT);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the rewards were moved into a vesting stream
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.balance, BALANCE - AMOUNT);
+    let vesting = get_vesting(&mut context, alice.pubkey()).await;
+    assert_eq!(vesting.authority, alice.pubkey());
+    assert_eq!(vesting.proof, proof_address(alice.pubkey()));
+    assert_eq!(
+        vesting.bump,
+        vesting_pda(proof_address(alice.pubkey())).1 as u64
+    );
+    assert_eq!(vesting.unlocked, 0);
+    assert_eq!(vesting.locked, AMOUNT);
+    assert_eq!(vesting.ends_at, NOW + ONE_DAY);
+
+    // Submit withdraw ix a quarter of the way through the stream
+    set_clock(&mut context, NOW + ONE_DAY / 4).await;
+    let beneficiary = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let ix = withdraw(alice.pubkey(), beneficiary, AMOUNT / 4);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the vested quarter was withdrawn
+    assert_eq!(
+        get_token_balance(&mut context, alice.pubkey()).await,
+        AMOUNT / 4
+    );
+    let vesting = get_vesting(&mut context, alice.pubkey()).await;
+    assert_eq!(vesting.unlocked, 0);
+    assert_eq!(vesting.locked, AMOUNT - AMOUNT / 4);
+
+    // Submit withdraw ix for more than has vested
+    let ix = withdraw(alice.pubkey(), beneficiary, 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Submit withdraw ix for the remainder once the stream has ended
+    set_clock(&mut context, NOW + ONE_DAY * 2).await;
+    let ix = withdraw(alice.pubkey(), beneficiary, AMOUNT - AMOUNT / 4);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_ok());
+
+    // Assert the stream is empty
+    let vesting = get_vesting(&mut context, alice.pubkey()).await;
+    assert_eq!(vesting.unlocked, 0);
+    assert_eq!(vesting.locked, 0);
+    assert_eq!(
+        get_token_balance(&mut context, alice.pubkey()).await,
+        AMOUNT
+    );
+
+    // Submit withdraw ix from the empty stream
+    let ix = withdraw(alice.pubkey(), beneficiary, 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert lifetime claims were recorded
+    let treasury_account = context
+        .banks_client
+        .get_account(TREASURY_ADDRESS)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury = Treasury::try_from_bytes(&treasury_account.data).unwrap();
+    assert_eq!(treasury.total_claimed, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_vest_too_large() {
+    // Setup
+    let (mut context, alice) = setup_program_test_env().await;
+
+    // Submit vest ix for more than the claimable balance
+    let ix = vest(alice.pubkey(), BALANCE + 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_vest_wrong_proof() {
+    // Setup
+    let (mut context, alice) = setup_program_test_env().await;
+
+    // Submit vest ix into alice's vesting account of another proof
+    let mut ix = vest(alice.pubkey(), AMOUNT);
+    ix.accounts[3].pubkey = vesting_pda(OTHER_PROOF_ADDRESS).0;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert the proof is untouched
+    let proof = get_proof(&mut context, alice.pubkey()).await;
+    assert_eq!(proof.balance, BALANCE);
+}
+
+#[tokio::test]
+async fn test_withdraw_wrong_vesting_address() {
+    // Setup
+    let (mut context, alice) = setup_program_test_env().await;
+
+    // Submit withdraw ix against a vesting account which is not at the proof's vesting PDA
+    let beneficiary = get_associated_token_address(&alice.pubkey(), &MINT_ADDRESS);
+    let mut ix = withdraw(alice.pubkey(), beneficiary, AMOUNT);
+    ix.accounts[3].pubkey = FAKE_VESTING_ADDRESS;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        context.last_blockhash,
+    );
+    let res = context.banks_client.process_transaction(tx).await;
+    assert!(res.is_err());
+
+    // Assert no tokens were transferred
+    assert_eq!(get_token_balance(&mut context, alice.pubkey()).await, 0);
+}
+
+fn vesting_pda(proof: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VESTING, proof.as_ref()], &ore::id())
+}
+
+async fn get_vesting(context: &mut ProgramTestContext, authority: Pubkey) -> Vesting {
+    let vesting_account = context
+        .banks_client
+        .get_account(vesting_pda(proof_address(authority)).0)
+        .await
+        .unwrap()
+        .unwrap();
+    *Vesting::try_from_bytes(&vesting_account.data).unwrap()
+}
+
+async fn setup_program_test_env() -> (ProgramTestContext, Keypair) {
+    let mut program_test = ProgramTest::new("ore", ore::ID, processor!(ore::process_instruction));
+
+    // Setup alice
+    let alice = Keypair::new();
+    add_payer(&mut program_test, alice.pubkey());
+    add_token_account(&mut program_test, alice.pubkey(), 0);
+
+    // Setup config
+    let mut config = Config::zeroed();
+    config.vesting_duration = ONE_DAY;
+    add_ore_account(&mut program_test, CONFIG_ADDRESS, config);
+
+    // Setup alice's proof, with claimable rewards
+    let mut proof = Proof::zeroed();
+    proof.authority = alice.pubkey();
+    proof.miner = alice.pubkey();
+    proof.balance = BALANCE;
+    add_ore_account(&mut program_test, proof_address(alice.pubkey()), proof);
+
+    // Setup fully vested streams for alice at an address other than her vesting PDA, and at the
+    // vesting PDA of another proof
+    for (address, proof) in [
+        (FAKE_VESTING_ADDRESS, proof_address(alice.pubkey())),
+        (vesting_pda(OTHER_PROOF_ADDRESS).0, OTHER_PROOF_ADDRESS),
+    ] {
+        let mut vesting = Vesting::zeroed();
+        vesting.authority = alice.pubkey();
+        vesting.proof = proof;
+        vesting.bump = vesting_pda(proof).1 as u64;
+        vesting.unlocked = AMOUNT;
+        vesting.updated_at = NOW;
+        vesting.ends_at = NOW;
+        add_ore_account(&mut program_test, address, vesting);
+    }
+
+    // Setup treasury, which holds the rewards
+    let mut treasury = Treasury::zeroed();
+    treasury.bump = TREASURY_BUMP as u64;
+    add_ore_account(&mut program_test, TREASURY_ADDRESS, treasury);
+    add_token_account(&mut program_test, TREASURY_ADDRESS, BALANCE);
+
+    let mut context = program_test.start_with_context().await;
+    set_clock(&mut context, NOW).await;
+    (context, alice)
+}